@@ -9,19 +9,38 @@
 
 use anyhow::anyhow;
 use astro_float::ctx::Context;
-use astro_float::{expr, BigFloat, Consts, RoundingMode};
-use std::str::FromStr;
+use astro_float::{expr, BigFloat, Consts, RoundingMode as AstroRoundingMode};
 
-pub const ROUNDING: RoundingMode = RoundingMode::ToEven;
+pub const ROUNDING: AstroRoundingMode = AstroRoundingMode::ToEven;
 pub const C_FLOAT: f64 = 299_792_458.0;
+/// Newtonian gravitational constant, m^3 kg^-1 s^-2 (2018 CODATA value)
+pub const G_FLOAT: f64 = 6.674_30e-11;
+
+/// Extra binary digits of guard precision used when reimplementing the hyperbolic
+/// functions from `exp`/`ln`/`sqrt`, so the final rounding step doesn't eat into the
+/// requested working precision
+const GUARD_BITS: usize = 64;
+
+/// Precision (binary digits) `bigfloat_from_str` targets, since it has no `Relativity`
+/// instance (and hence no configured precision) to draw on. Generous enough to cover
+/// every precision this crate's own constant/test setup uses (up to 300 decimal digits)
+const FROM_STR_BINARY_DIGITS: usize = 2048;
+
+/// Minimum precision (binary digits) needed to hold an `f64`'s mantissa exactly: its 52
+/// explicit fraction bits plus the implicit leading one
+const F64_EXACT_BINARY_DIGITS: usize = 53;
 
 pub struct Relativity {
     pub ctx: Context,
+    /// Higher-precision context used internally by the hand-rolled hyperbolic functions
+    guard_ctx: Context,
     binary_digits: usize,
+    guard_digits: usize,
 
     c: BigFloat,
     c_squared: BigFloat,
     g: BigFloat,
+    big_g: BigFloat,
     light_year: BigFloat,
     au: BigFloat,
     seconds_per_year: BigFloat,
@@ -102,12 +121,15 @@ impl Relativity {
         #[allow(clippy::cast_sign_loss)]
         #[allow(clippy::cast_precision_loss)]
         let binary_digits = (decimal_digits as f64 * 3.32) as usize;
+        let guard_digits = binary_digits + GUARD_BITS;
         let constants = Consts::new().expect("Failed to allocate constants cache");
+        let guard_constants = Consts::new().expect("Failed to allocate constants cache");
         let c = BigFloat::from_u32(299_792_458, binary_digits);
         let one = BigFloat::from_u32(1, binary_digits);
 
         Self {
             binary_digits,
+            guard_digits,
             ctx: Context::new(
                 binary_digits,
                 ROUNDING,
@@ -115,6 +137,13 @@ impl Relativity {
                 i32::MIN,
                 i32::MAX,
             ),
+            guard_ctx: Context::new(
+                guard_digits,
+                ROUNDING,
+                guard_constants,
+                i32::MIN,
+                i32::MAX,
+            ),
             c_squared: c.powi(2, binary_digits, ROUNDING),
             half: one.div(
                 &BigFloat::from_i32(2, binary_digits),
@@ -123,6 +152,7 @@ impl Relativity {
             ),
             one,
             c,
+            big_g: BigFloat::from_f64(G_FLOAT, binary_digits),
             g: Relativity::bigfloat_from_str("9.80665"),
             light_year: Relativity::bigfloat_from_str("9460730472580800"),
             au: Relativity::bigfloat_from_str("149597870700"),
@@ -141,6 +171,11 @@ impl Relativity {
         &self.g
     }
     #[inline]
+    /// Newtonian gravitational constant G
+    pub fn get_big_g(&self) -> &BigFloat {
+        &self.big_g
+    }
+    #[inline]
     pub fn get_light_year(&self) -> &BigFloat {
         &self.light_year
     }
@@ -181,6 +216,166 @@ impl Relativity {
         expr!(fraction * c, &mut self.ctx)
     }
 
+    // ============= Hyperbolic and inverse hyperbolic functions =================
+    //
+    // astro-float's built-in cosh/sinh/tanh/acosh/asinh/atanh lose most of their
+    // precision for arguments like 23.123, matching C#/Python/Wolfram to only 8-38
+    // decimal places at 300dp instead of the 85-90 they agree to. These reimplement
+    // the same functions from the accurate exp/ln primitives at a higher "guard"
+    // precision, rounding back down to the working precision at the end, which keeps
+    // rapidity<->velocity conversions trustworthy at the advertised precision.
+
+    /// exp(x) - 1, formed as that plain difference at guard precision (not a separate
+    /// series): the guard bits absorb the cancellation for moderately small x, but for
+    /// |x| well below 2^-GUARD_BITS the result drops below the working precision
+    fn expm1(&mut self, x: &BigFloat) -> BigFloat {
+        let one = &self.one;
+        expr!(exp(x) - one, &mut self.guard_ctx)
+    }
+
+    /// ln(1 + x), with `1 + x` formed directly at guard precision, so for |x| well below
+    /// 2^-GUARD_BITS the low bits of x are lost to that rounding
+    fn ln1p(&mut self, x: &BigFloat) -> BigFloat {
+        let one = &self.one;
+        expr!(ln(one + x), &mut self.guard_ctx)
+    }
+
+    /// Round a guard-precision intermediate value back down to the working precision
+    fn round_to_working(&self, x: &BigFloat) -> BigFloat {
+        x.div(&self.one, self.binary_digits, ROUNDING)
+    }
+
+    /// Hyperbolic cosine: (e^x + e^-x) / 2
+    pub fn cosh(&mut self, x: &BigFloat) -> BigFloat {
+        let neg_x = expr!(-x, &mut self.guard_ctx);
+        let neg_x = &neg_x;
+        let result = expr!((exp(x) + exp(neg_x)) / 2, &mut self.guard_ctx);
+        self.round_to_working(&result)
+    }
+
+    /// Hyperbolic sine. Computed as (expm1(x) - expm1(-x)) / 2 for small |x|, or
+    /// directly from exp for larger |x|; both forms are evaluated at guard precision
+    pub fn sinh(&mut self, x: &BigFloat) -> BigFloat {
+        let half = Relativity::bigfloat_from_str("0.5");
+        let result = if x.abs().ge(&half) {
+            let neg_x = expr!(-x, &mut self.guard_ctx);
+            let neg_x = &neg_x;
+            expr!((exp(x) - exp(neg_x)) / 2, &mut self.guard_ctx)
+        } else {
+            let neg_x = expr!(-x, &mut self.guard_ctx);
+            let t = self.expm1(x);
+            let s = self.expm1(&neg_x);
+            let t = &t;
+            let s = &s;
+            expr!((t - s) / 2, &mut self.guard_ctx)
+        };
+        self.round_to_working(&result)
+    }
+
+    /// Hyperbolic tangent. Computed as expm1(2x)/(expm1(2x)+2) for small |x|, or
+    /// sinh(x)/cosh(x) for larger |x|
+    pub fn tanh(&mut self, x: &BigFloat) -> BigFloat {
+        let half = Relativity::bigfloat_from_str("0.5");
+        if x.abs().ge(&half) {
+            let s = self.sinh(x);
+            let c = self.cosh(x);
+            let s = &s;
+            let c = &c;
+            let result = expr!(s / c, &mut self.guard_ctx);
+            self.round_to_working(&result)
+        } else {
+            let two_x = expr!(x * 2, &mut self.guard_ctx);
+            let e = self.expm1(&two_x);
+            let e = &e;
+            let result = expr!(e / (e + 2), &mut self.guard_ctx);
+            self.round_to_working(&result)
+        }
+    }
+
+    /// Inverse hyperbolic sine: sign(x) * ln(|x| + sqrt(x^2 + 1))
+    pub fn asinh(&mut self, x: &BigFloat) -> BigFloat {
+        let zero = BigFloat::from_i32(0, self.binary_digits);
+        let negative = !x.ge(&zero);
+        let abs_x = x.abs();
+        let abs_x = &abs_x;
+        let arg = expr!(abs_x + sqrt(abs_x * abs_x + 1), &mut self.guard_ctx);
+        let arg = &arg;
+        let result = expr!(ln(arg), &mut self.guard_ctx);
+        let result = self.round_to_working(&result);
+        if negative {
+            let result = &result;
+            expr!(-result, &mut self.ctx)
+        } else {
+            result
+        }
+    }
+
+    /// Inverse hyperbolic cosine, for x >= 1: ln(x + sqrt((x-1)*(x+1))), switching to
+    /// `ln1p` near x = 1 to avoid cancellation as the argument of `ln` approaches 1
+    pub fn acosh(&mut self, x: &BigFloat) -> BigFloat {
+        assert!(x.ge(&self.one), "acosh(x) is only defined for x >= 1");
+        let one = &self.one;
+        let onehalf = Relativity::bigfloat_from_str("1.5");
+        let result = if x.ge(&onehalf) {
+            expr!(ln(x + sqrt((x - one) * (x + one))), &mut self.guard_ctx)
+        } else {
+            // x close to 1: with t = x - 1, x + sqrt((x-1)(x+1)) = 1 + t + sqrt(t*(t+2)),
+            // so feed t + sqrt(t*(t+2)) into ln1p
+            let t = expr!(x - one, &mut self.guard_ctx);
+            let t = &t;
+            let s = expr!(sqrt(t * (t + 2)), &mut self.guard_ctx);
+            let s = &s;
+            let arg = expr!(t + s, &mut self.guard_ctx);
+            self.ln1p(&arg)
+        };
+        self.round_to_working(&result)
+    }
+
+    /// Inverse hyperbolic tangent: 0.5 * ln1p(2x / (1-x)), requires |x| < 1
+    pub fn atanh(&mut self, x: &BigFloat) -> BigFloat {
+        assert!(!x.abs().ge(&self.one), "atanh(x) is only defined for |x| < 1");
+        let one = &self.one;
+        let arg = expr!((x * 2) / (one - x), &mut self.guard_ctx);
+        let result = self.ln1p(&arg);
+        let result = &result;
+        let result = expr!(result * 0.5, &mut self.guard_ctx);
+        self.round_to_working(&result)
+    }
+
+    // ============= General relativity: gravitational time dilation =================
+
+    /// Schwarzschild radius (m) of a body of the given mass (kg): `2GM/c^2`
+    pub fn schwarzschild_radius(&mut self, mass: &BigFloat) -> BigFloat {
+        let big_g = &self.big_g;
+        let c_squared = &self.c_squared;
+        expr!((2 * big_g * mass) / c_squared, &mut self.ctx)
+    }
+
+    /// Gravitational time dilation: the ratio of a clock's proper rate at radial
+    /// distance `r` (m) from a body of the given `mass` (kg) to a clock at infinity,
+    /// `sqrt(1 - r_s/r)`. Panics if `r` is at or inside the Schwarzschild radius
+    pub fn gravitational_time_dilation(&mut self, mass: &BigFloat, r: &BigFloat) -> BigFloat {
+        let r_s = self.schwarzschild_radius(mass);
+        assert!(!r_s.ge(r), "r must be greater than the Schwarzschild radius");
+        let r_s = &r_s;
+        let one = &self.one;
+        expr!(sqrt(one - r_s / r), &mut self.ctx)
+    }
+
+    /// Combined gravitational + special-relativistic clock rate for an object orbiting
+    /// (or otherwise moving) at radius `r` (m) and velocity `v` (m/s) relative to a body
+    /// of the given `mass` (kg), versus a static clock at infinity. This is the
+    /// fractional rate difference seen between e.g. a GPS satellite and a ground clock
+    pub fn orbital_clock_rate(&mut self, mass: &BigFloat, r: &BigFloat, v: &BigFloat) -> BigFloat {
+        let gravitational = self.gravitational_time_dilation(mass, r);
+        let special = self.lorentz_factor(v);
+        let gravitational = &gravitational;
+        let special = &special;
+        // divide by the Lorentz factor rather than multiply by its inverse explicitly,
+        // since gamma = 1/sqrt(1 - v^2/c^2)
+        expr!(gravitational / special, &mut self.ctx)
+    }
+
     // ============= Special relativity functions =================
 
     /// Calculate proper time (sec) to reach a given velocity under constant proper acceleration
@@ -250,24 +445,61 @@ impl Relativity {
         // atanh(velocity / c)
         self.check_velocity(velocity);
         let c = &self.c;
-        expr!(atanh(velocity / c), &mut self.ctx)
+        let fraction = expr!(velocity / c, &mut self.ctx);
+        self.atanh(&fraction)
     }
 
     /// Velocity (m/s) from rapidity
     pub fn velocity_from_rapidity(&mut self, rapidity: &BigFloat) -> BigFloat {
         // c * tanh(rapidity)
+        let fraction = self.tanh(rapidity);
         let c = &self.c;
-        let v = expr!(c * tanh(rapidity), &mut self.ctx);
+        let fraction = &fraction;
+        let v = expr!(c * fraction, &mut self.ctx);
         self.check_velocity_msg(&v, "Precision failure in velocity_from_rapidity");
         v
     }
 
-    // Add two velocities (m/s) using relativistic velocity addition
+    /// Add two velocities (m/s) using relativistic velocity addition, implemented via
+    /// rapidity addition (`atanh(v1/c) + atanh(v2/c)`, then `tanh`*c) so it stays
+    /// accurate as the velocities approach c, unlike the direct formula
+    /// `(v1 + v2) / (1 + v1*v2/c^2)`
     pub fn add_velocities(&mut self, v1: &BigFloat, v2: &BigFloat) -> BigFloat {
-        // (v1 + v2) / (one + (v1 * v2) / csquared)
+        let r1 = self.rapidity_from_velocity(v1);
+        let r2 = self.rapidity_from_velocity(v2);
+        let total = self.compose_rapidities(&[r1, r2]);
+        self.velocity_from_rapidity(&total)
+    }
+
+    /// Compose a sequence of rapidities into a single total rapidity. Rapidities add
+    /// linearly under boost composition, which is the main reason to do velocity
+    /// addition in rapidity space rather than directly with velocities
+    pub fn compose_rapidities(&mut self, rapidities: &[BigFloat]) -> BigFloat {
+        let mut total = self.bigfloat_from_u64(0);
+        for r in rapidities {
+            let total_ref = &total;
+            total = expr!(total_ref + r, &mut self.ctx);
+        }
+        total
+    }
+
+    /// Apply a 1D Lorentz boost at velocity `v` (m/s) to a spacetime event, using the
+    /// boost matrix `(gamma, -gamma*beta; -gamma*beta, gamma)`. Reuses the same
+    /// `SimplifiedInterval` type as `spacetime_interval_1d_f64`, so callers can verify
+    /// the interval stays invariant under the boost they just applied
+    pub fn lorentz_boost_1d(&mut self, event: &SimplifiedInterval, v: &BigFloat) -> SimplifiedInterval {
+        self.check_velocity(v);
+        let gamma = self.lorentz_factor(v);
+        let gamma = &gamma;
         let c_squared = &self.c_squared;
-        let one = &self.one;
-        expr!((v1 + v2) / (one + (v1 * v2) / c_squared), &mut self.ctx)
+        let (time, x) = event.destructure();
+
+        let time_prime = expr!(gamma * (time - (v * x) / c_squared), &mut self.ctx);
+        let x_prime = expr!(gamma * (x - v * time), &mut self.ctx);
+        SimplifiedInterval {
+            time: time_prime,
+            x: x_prime,
+        }
     }
 
     /// Coordinate time elapsed (s) from proper acceleration (m/s^2) and proper time (s)
@@ -455,9 +687,135 @@ impl Relativity {
         BigFloat::from_f64(n, self.binary_digits)
     }
 
+    /// Capture the *exact* dyadic value an IEEE-754 double represents, rather than the
+    /// decimal literal that produced it: `0.1` becomes `0.1000000000000000055511151231...`,
+    /// not `0.1`. Built by reading the 52-bit mantissa and unbiased exponent straight out
+    /// of `n`'s bit pattern and scaling by the matching power of two, which can never
+    /// lose precision — but only if the working precision can hold all 53 significant
+    /// bits of that mantissa, so this always uses at least `F64_EXACT_BINARY_DIGITS`
+    /// regardless of this struct's own (possibly much smaller) configured precision
+    pub fn bigfloat_from_f64_exact(&self, n: f64) -> BigFloat {
+        let precision = self.binary_digits.max(F64_EXACT_BINARY_DIGITS);
+
+        if n == 0.0 || n.is_nan() || n.is_infinite() {
+            // zero needs no mantissa bits to represent exactly, and NaN/infinity have no
+            // exact dyadic value to build in the first place; both are already handled
+            // correctly by the library's own conversion
+            return BigFloat::from_f64(n, precision);
+        }
+
+        let bits = n.to_bits();
+        let sign_negative = (bits >> 63) & 1 == 1;
+        let raw_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let raw_mantissa = bits & 0x000f_ffff_ffff_ffff;
+
+        // normal numbers have an implicit leading 1 bit that subnormals don't, and
+        // subnormals use the minimum exponent instead of `raw_exponent - 1023`
+        let (mantissa, exponent): (u64, i32) = if raw_exponent == 0 {
+            (raw_mantissa, -1022 - 52)
+        } else {
+            (raw_mantissa | (1 << 52), raw_exponent - 1023 - 52)
+        };
+
+        let mantissa_big = BigFloat::from_u64(mantissa, precision);
+        let two = BigFloat::from_u32(2, precision);
+        let magnitude = if exponent >= 0 {
+            mantissa_big.mul(&two.powi(exponent, precision, ROUNDING), precision, ROUNDING)
+        } else {
+            mantissa_big.div(&two.powi(-exponent, precision, ROUNDING), precision, ROUNDING)
+        };
+
+        if sign_negative {
+            magnitude.mul(&BigFloat::from_i32(-1, precision), precision, ROUNDING)
+        } else {
+            magnitude
+        }
+    }
+
+    /// Take the shortest decimal that round-trips to `n` (what Rust's own `{n}` display
+    /// prints) and keep only `sig_digits` significant digits of *that* decimal, rounding
+    /// half to even: `0.1` stays `0.1` rather than exposing its binary noise. Use this
+    /// when the caller's intent is the human-written decimal, not the underlying bit
+    /// pattern; use `bigfloat_from_f64_exact` when it's the bit pattern itself
+    pub fn bigfloat_from_f64_rounded(&self, n: f64, sig_digits: usize) -> BigFloat {
+        let shortest = format!("{n}");
+        let rounded = round_to_significant_digits(&shortest, sig_digits);
+        bigfloat_from_decimal_str(&rounded, self.binary_digits)
+    }
+
     #[inline]
     pub fn bigfloat_from_str(s: &str) -> BigFloat {
-        BigFloat::from_str(s).unwrap()
+        bigfloat_from_decimal_str(s, FROM_STR_BINARY_DIGITS)
+    }
+
+    /// Parse a decimal number with an optional trailing unit suffix into a `BigFloat` in
+    /// SI base units: `c` for a fraction of the speed of light (-> m/s), `ly`/`au` for a
+    /// distance (-> m), `g` for an acceleration in multiples of standard gravity
+    /// (-> m/s^2). A bare number with no suffix is returned unconverted. Returns a
+    /// descriptive error instead of panicking on malformed input, and rejects
+    /// `c`-relative velocities `>= 1` rather than asserting
+    pub fn parse_quantity(&self, s: &str) -> anyhow::Result<BigFloat> {
+        let trimmed = s.trim();
+        let suffix_start = trimmed
+            .rfind(|c: char| !c.is_ascii_alphabetic())
+            .map_or(0, |i| i + 1);
+        let (number, suffix) = trimmed.split_at(suffix_start);
+
+        if number.is_empty() {
+            return Err(anyhow!("Missing numeric value in '{s}'"));
+        }
+        validate_decimal_number(number)
+            .map_err(|e| anyhow!("Invalid numeric value '{number}' in '{s}': {e}"))?;
+        let value = Relativity::bigfloat_from_str(number);
+
+        match suffix {
+            "" => Ok(value),
+            "c" => {
+                if value.abs().ge(&self.one) {
+                    Err(anyhow!("'{s}' is a velocity >= c, which is not physical"))
+                } else {
+                    Ok(value.mul(&self.c, self.binary_digits, ROUNDING))
+                }
+            }
+            "ly" => Ok(value.mul(&self.light_year, self.binary_digits, ROUNDING)),
+            "au" => Ok(value.mul(&self.au, self.binary_digits, ROUNDING)),
+            "g" => Ok(value.mul(&self.g, self.binary_digits, ROUNDING)),
+            other => Err(anyhow!("Unrecognised unit suffix '{other}' in '{s}'")),
+        }
+    }
+
+    /// Build an iterator of `count` values starting at `start` and advancing by `step` in
+    /// arbitrary-precision arithmetic, not `count` accumulated `f64` additions: `start`/`step`
+    /// are parsed via `bigfloat_from_decimal_str` rather than through `f64`, so the step
+    /// carries only the error of one correctly-rounded conversion at `binary_digits` (a
+    /// decimal like `0.1` has no exact binary value), not an `f64`'s. The running total is still
+    /// binary `BigFloat` arithmetic, so it isn't base-10-exact — stepping `0.0` by `0.1` a
+    /// thousand times won't land on a bit pattern equal to `100.0` — but at `binary_digits`
+    /// of working precision the accumulated error stays far below the last formatted decimal
+    /// place, so formatting every value to `decimal_places` (see below) is stable
+    pub fn bigfloat_range(
+        &self,
+        start: &str,
+        step: &str,
+        count: usize,
+    ) -> anyhow::Result<BigFloatRange> {
+        validate_decimal_number(start)
+            .map_err(|e| anyhow!("Invalid range start '{start}': {e}"))?;
+        validate_decimal_number(step).map_err(|e| anyhow!("Invalid range step '{step}': {e}"))?;
+
+        let (step_mantissa, step_exponent) = step.split_once(['e', 'E']).unwrap_or((step, ""));
+        let step_exponent: i32 = step_exponent.parse().unwrap_or(0);
+        let decimal_places = (split_decimal(step_mantissa).1.len() as i32)
+            .saturating_sub(step_exponent)
+            .max(0);
+
+        Ok(BigFloatRange {
+            current: bigfloat_from_decimal_str(start, self.binary_digits),
+            step: bigfloat_from_decimal_str(step, self.binary_digits),
+            remaining: count,
+            binary_digits: self.binary_digits,
+            decimal_places,
+        })
     }
 
     #[inline]
@@ -486,111 +844,1086 @@ impl Relativity {
     // }
 }
 
+// ============= Generic numeric backend =================
+//
+// `Relativity` above is hard-wired to `BigFloat`, so arbitrary-precision cost is paid
+// even when `f64` accuracy suffices. `RelativityScalar` captures the operations the
+// simpler (non-guard-precision) equations need, and `GenericRelativity<T>` reimplements
+// them against the trait instead of the `expr!`/`Context` machinery, so the same call
+// sites work against a fast `GenericRelativity<f64>` or a `GenericRelativity<BigFloat>`.
+// Note this generic path uses astro-float's *built-in* cosh/sinh/tanh/acosh/atanh
+// directly (no guard digits), so it inherits their documented precision loss versus the
+// hand-rolled `Relativity::{cosh,sinh,tanh,...}` methods above; reach for the concrete
+// `Relativity` when that difference matters.
+
+/// Numeric backend for `GenericRelativity`: the arithmetic, comparisons, and hyperbolic
+/// functions the special- and general-relativity equations need. Every operation except
+/// `rs_abs`/`rs_ge` takes an explicit `precision`, mirroring `BigFloat`'s own API; `f64`
+/// simply ignores it
+pub trait RelativityScalar: Clone {
+    fn rs_from_f64(v: f64, precision: usize) -> Self;
+    fn rs_add(&self, other: &Self, precision: usize) -> Self;
+    fn rs_sub(&self, other: &Self, precision: usize) -> Self;
+    fn rs_mul(&self, other: &Self, precision: usize) -> Self;
+    fn rs_div(&self, other: &Self, precision: usize) -> Self;
+    fn rs_powi(&self, n: i32, precision: usize) -> Self;
+    fn rs_sqrt(&self, precision: usize) -> Self;
+    fn rs_abs(&self) -> Self;
+    fn rs_ge(&self, other: &Self) -> bool;
+    fn rs_tanh(&self, precision: usize) -> Self;
+    fn rs_atanh(&self, precision: usize) -> Self;
+    fn rs_cosh(&self, precision: usize) -> Self;
+    fn rs_acosh(&self, precision: usize) -> Self;
+    fn rs_sinh(&self, precision: usize) -> Self;
+}
+
+impl RelativityScalar for f64 {
+    #[inline]
+    fn rs_from_f64(v: f64, _precision: usize) -> Self {
+        v
+    }
+    #[inline]
+    fn rs_add(&self, other: &Self, _precision: usize) -> Self {
+        self + other
+    }
+    #[inline]
+    fn rs_sub(&self, other: &Self, _precision: usize) -> Self {
+        self - other
+    }
+    #[inline]
+    fn rs_mul(&self, other: &Self, _precision: usize) -> Self {
+        self * other
+    }
+    #[inline]
+    fn rs_div(&self, other: &Self, _precision: usize) -> Self {
+        self / other
+    }
+    #[inline]
+    fn rs_powi(&self, n: i32, _precision: usize) -> Self {
+        self.powi(n)
+    }
+    #[inline]
+    fn rs_sqrt(&self, _precision: usize) -> Self {
+        self.sqrt()
+    }
+    #[inline]
+    fn rs_abs(&self) -> Self {
+        f64::abs(*self)
+    }
+    #[inline]
+    fn rs_ge(&self, other: &Self) -> bool {
+        self >= other
+    }
+    #[inline]
+    fn rs_tanh(&self, _precision: usize) -> Self {
+        self.tanh()
+    }
+    #[inline]
+    fn rs_atanh(&self, _precision: usize) -> Self {
+        self.atanh()
+    }
+    #[inline]
+    fn rs_cosh(&self, _precision: usize) -> Self {
+        self.cosh()
+    }
+    #[inline]
+    fn rs_acosh(&self, _precision: usize) -> Self {
+        self.acosh()
+    }
+    #[inline]
+    fn rs_sinh(&self, _precision: usize) -> Self {
+        self.sinh()
+    }
+}
+
+impl RelativityScalar for BigFloat {
+    #[inline]
+    fn rs_from_f64(v: f64, precision: usize) -> Self {
+        BigFloat::from_f64(v, precision)
+    }
+    #[inline]
+    fn rs_add(&self, other: &Self, precision: usize) -> Self {
+        self.add(other, precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_sub(&self, other: &Self, precision: usize) -> Self {
+        self.sub(other, precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_mul(&self, other: &Self, precision: usize) -> Self {
+        self.mul(other, precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_div(&self, other: &Self, precision: usize) -> Self {
+        self.div(other, precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_powi(&self, n: i32, precision: usize) -> Self {
+        self.powi(n, precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_sqrt(&self, precision: usize) -> Self {
+        self.sqrt(precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_abs(&self) -> Self {
+        self.abs()
+    }
+    #[inline]
+    fn rs_ge(&self, other: &Self) -> bool {
+        self.ge(other)
+    }
+    #[inline]
+    fn rs_tanh(&self, precision: usize) -> Self {
+        self.tanh(precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_atanh(&self, precision: usize) -> Self {
+        self.atanh(precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_cosh(&self, precision: usize) -> Self {
+        self.cosh(precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_acosh(&self, precision: usize) -> Self {
+        self.acosh(precision, ROUNDING)
+    }
+    #[inline]
+    fn rs_sinh(&self, precision: usize) -> Self {
+        self.sinh(precision, ROUNDING)
+    }
+}
+
+/// Special- and general-relativity toolkit generic over its numeric backend `T`. Mirrors
+/// a subset of `Relativity`'s scalar-in-scalar-out formulas; use `GenericRelativity<f64>`
+/// for fast interactive sweeps and `GenericRelativity<BigFloat>` for higher-precision
+/// results from the same call sites
+pub struct GenericRelativity<T: RelativityScalar> {
+    precision: usize,
+    c: T,
+    c_squared: T,
+    g: T,
+    big_g: T,
+    one: T,
+}
+
+impl<T: RelativityScalar> GenericRelativity<T> {
+    /// Setup with the specified number of decimal digits of precision. Only meaningful
+    /// for `T = BigFloat`; `T = f64` ignores it and always has ~16 significant digits
+    pub fn new(decimal_digits: usize) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_sign_loss)]
+        #[allow(clippy::cast_precision_loss)]
+        let precision = (decimal_digits as f64 * 3.32) as usize;
+        let c = T::rs_from_f64(C_FLOAT, precision);
+        let c_squared = c.rs_mul(&c, precision);
+        Self {
+            precision,
+            c,
+            c_squared,
+            g: T::rs_from_f64(9.80665, precision),
+            big_g: T::rs_from_f64(G_FLOAT, precision),
+            one: T::rs_from_f64(1.0, precision),
+        }
+    }
+
+    #[inline]
+    pub fn get_c(&self) -> &T {
+        &self.c
+    }
+
+    #[inline]
+    /// Ensure velocity is less than c
+    fn check_velocity(&self, velocity: &T) {
+        assert!(!velocity.rs_abs().rs_ge(&self.c), "Velocity must be less than c");
+    }
+
+    /// Calculate the Lorentz factor from a velocity (m/s)
+    pub fn lorentz_factor(&self, velocity: &T) -> T {
+        self.check_velocity(velocity);
+        let ratio_sq = velocity.rs_div(&self.c, self.precision).rs_powi(2, self.precision);
+        let inside = self.one.rs_sub(&ratio_sq, self.precision).rs_sqrt(self.precision);
+        self.one.rs_div(&inside, self.precision)
+    }
+
+    /// Rapidity from velocity (m/s)
+    pub fn rapidity_from_velocity(&self, velocity: &T) -> T {
+        self.check_velocity(velocity);
+        velocity.rs_div(&self.c, self.precision).rs_atanh(self.precision)
+    }
+
+    /// Velocity (m/s) from rapidity
+    pub fn velocity_from_rapidity(&self, rapidity: &T) -> T {
+        self.c.rs_mul(&rapidity.rs_tanh(self.precision), self.precision)
+    }
+
+    /// Add two velocities (m/s) using relativistic velocity addition, via rapidity
+    /// addition as in `Relativity::add_velocities`
+    pub fn add_velocities(&self, v1: &T, v2: &T) -> T {
+        let r1 = self.rapidity_from_velocity(v1);
+        let r2 = self.rapidity_from_velocity(v2);
+        self.velocity_from_rapidity(&r1.rs_add(&r2, self.precision))
+    }
+
+    /// Contracted length (m) from proper length (m) and velocity (m/s)
+    pub fn length_contraction_velocity(&self, len: &T, velocity: &T) -> T {
+        self.check_velocity(velocity);
+        let ratio_sq = velocity.rs_div(&self.c, self.precision).rs_powi(2, self.precision);
+        let inside = self.one.rs_sub(&ratio_sq, self.precision).rs_sqrt(self.precision);
+        len.rs_mul(&inside, self.precision)
+    }
+
+    /// Calculate the relativistic momentum (kg m/s) from mass (kg) and velocity (m/s)
+    pub fn relativistic_momentum(&self, mass: &T, velocity: &T) -> T {
+        self.check_velocity(velocity);
+        let gamma = self.lorentz_factor(velocity);
+        mass.rs_mul(velocity, self.precision).rs_mul(&gamma, self.precision)
+    }
+
+    /// Calculate the relativistic energy in joules from mass (kg) and velocity (m/s)
+    pub fn relativistic_energy(&self, mass: &T, velocity: &T) -> T {
+        self.check_velocity(velocity);
+        let gamma = self.lorentz_factor(velocity);
+        mass.rs_mul(&self.c_squared, self.precision).rs_mul(&gamma, self.precision)
+    }
+
+    /// Calculate proper time (s) to reach a given velocity under constant proper acceleration
+    pub fn tau_to_velocity(&self, accel: &T, velocity: &T) -> T {
+        let fraction = velocity.rs_div(&self.c, self.precision).rs_atanh(self.precision);
+        self.c.rs_div(accel, self.precision).rs_mul(&fraction, self.precision)
+    }
+
+    /// Relativistic velocity (m/s) from acceleration (m/s^2) and proper time (s)
+    pub fn relativistic_velocity(&self, accel: &T, tau: &T) -> T {
+        let x = accel.rs_mul(tau, self.precision).rs_div(&self.c, self.precision);
+        self.c.rs_mul(&x.rs_tanh(self.precision), self.precision)
+    }
+
+    /// Distance (m) from proper acceleration (m/s^2) and proper time (s)
+    pub fn relativistic_distance(&self, accel: &T, tau: &T) -> T {
+        let x = accel.rs_mul(tau, self.precision).rs_div(&self.c, self.precision);
+        let cosh_minus_one = x.rs_cosh(self.precision).rs_sub(&self.one, self.precision);
+        self.c_squared.rs_div(accel, self.precision).rs_mul(&cosh_minus_one, self.precision)
+    }
+
+    /// Coordinate time elapsed (s) from proper acceleration (m/s^2) and proper time (s)
+    pub fn coordinate_time(&self, accel: &T, tau: &T) -> T {
+        let x = accel.rs_mul(tau, self.precision).rs_div(&self.c, self.precision);
+        self.c.rs_div(accel, self.precision).rs_mul(&x.rs_sinh(self.precision), self.precision)
+    }
+
+    /// Proper time (s) from relativistic acceleration (m/s^2) and distance (m)
+    pub fn relativistic_time_for_distance(&self, accel: &T, dist: &T) -> T {
+        let x = dist.rs_mul(accel, self.precision).rs_div(&self.c_squared, self.precision);
+        let arg = x.rs_add(&self.one, self.precision).rs_acosh(self.precision);
+        self.c.rs_div(accel, self.precision).rs_mul(&arg, self.precision)
+    }
+
+    /// Schwarzschild radius (m) of a body of the given mass (kg): `2GM/c^2`
+    pub fn schwarzschild_radius(&self, mass: &T) -> T {
+        let two = T::rs_from_f64(2.0, self.precision);
+        two.rs_mul(&self.big_g, self.precision)
+            .rs_mul(mass, self.precision)
+            .rs_div(&self.c_squared, self.precision)
+    }
+
+    /// Gravitational time dilation: the ratio of a clock's proper rate at radial
+    /// distance `r` (m) from a body of the given `mass` (kg) to a clock at infinity,
+    /// `sqrt(1 - r_s/r)`. Panics if `r` is at or inside the Schwarzschild radius
+    pub fn gravitational_time_dilation(&self, mass: &T, r: &T) -> T {
+        let r_s = self.schwarzschild_radius(mass);
+        assert!(!r_s.rs_ge(r), "r must be greater than the Schwarzschild radius");
+        let inside = self.one.rs_sub(&r_s.rs_div(r, self.precision), self.precision);
+        inside.rs_sqrt(self.precision)
+    }
+
+    /// Combined gravitational + special-relativistic clock rate, as
+    /// `Relativity::orbital_clock_rate`
+    pub fn orbital_clock_rate(&self, mass: &T, r: &T, v: &T) -> T {
+        let gravitational = self.gravitational_time_dilation(mass, r);
+        let special = self.lorentz_factor(v);
+        gravitational.rs_div(&special, self.precision)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How to position the decimal point when formatting a `BigFloat`
+pub enum ExponentMode {
+    /// Plain decimal notation, e.g. `1234.5`
+    Fixed,
+    /// Scientific notation, e.g. `1.2345e3`
+    Scientific,
+    /// Fixed notation while the value's power-of-ten exponent stays within
+    /// `threshold`, scientific notation beyond it
+    Auto { threshold: i32 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How `BigFloatFormat::digits` counts digits in the fractional part
+pub enum DigitMode {
+    /// `digits` counts every digit after the decimal point
+    FixedDecimals,
+    /// `digits` counts only after skipping a leading run of `skip_char` (e.g. `'9'` to
+    /// show digits beyond the leading nines of a value like `0.999999996`)
+    SignificantDigits { skip_char: char },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How `bigfloat_fmt_styled` clusters the integer part's digits for grouping
+pub enum GroupingPattern {
+    /// Every three digits, throughout (US/SI style, e.g. `1,234,567`)
+    Fixed3,
+    /// The least-significant three digits, then every two thereafter (Indian lakh/crore
+    /// style, e.g. `12,34,567`)
+    Indian,
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Locale/style descriptor for `bigfloat_fmt_styled`: which characters separate groups
+/// and mark the radix point, how the integer part's digits are clustered, and the
+/// minimum number of integer digits to show (the value is zero-padded on the left if it
+/// doesn't reach this)
+pub struct NumberFormat {
+    pub grouping_separator: char,
+    pub decimal_point: char,
+    pub grouping_pattern: GroupingPattern,
+    pub minimum_integer_digits: usize,
+}
+
+impl Default for NumberFormat {
+    /// US style: `1,234,567.89`
+    fn default() -> Self {
+        NumberFormat {
+            grouping_separator: ',',
+            decimal_point: '.',
+            grouping_pattern: GroupingPattern::Fixed3,
+            minimum_integer_digits: 1,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// US style: `1,234,567.89`
+    pub fn us() -> Self {
+        Self::default()
+    }
+
+    /// European style: `1.234.567,89`
+    pub fn european() -> Self {
+        NumberFormat {
+            grouping_separator: '.',
+            decimal_point: ',',
+            ..Self::default()
+        }
+    }
+
+    /// Indian (lakh/crore) style: `12,34,567.89`
+    pub fn indian() -> Self {
+        NumberFormat {
+            grouping_pattern: GroupingPattern::Indian,
+            ..Self::default()
+        }
+    }
+
+    /// SI style, space-grouped: `1 234 567.89`
+    pub fn si() -> Self {
+        NumberFormat {
+            grouping_separator: ' ',
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Rounding applied to the digit(s) beyond the requested count when formatting a
+/// `BigFloat` as a decimal string
+pub enum RoundingMode {
+    /// Round half to even (banker's rounding): an exact `...5000` tie resolves toward
+    /// the even neighbor. The IEEE/decimal standard, matching the crate's `ROUNDING`
+    /// used for `BigFloat` arithmetic, and the default for the existing `bigfloat_fmt*`
+    /// functions
+    HalfEven,
+    /// Round half away from zero: an exact tie always rounds up in magnitude
+    HalfUp,
+    /// Round half toward zero: an exact tie always rounds down in magnitude
+    HalfDown,
+    /// Always round toward positive infinity
+    Ceil,
+    /// Always round toward negative infinity
+    Floor,
+    /// Always round toward zero, dropping the extra digits outright
+    TowardZero,
+}
+
+/// Builder for rendering a `BigFloat` to a string: exponent mode, digit-count mode,
+/// thousands grouping, and a rounding mode. Replaces the old `internal_bigfloat_fmt`,
+/// which truncated unconditionally, so its last displayed digit was wrong whenever the
+/// dropped tail should have rounded up
+#[derive(Debug, Clone, Copy)]
+pub struct BigFloatFormat {
+    exponent_mode: ExponentMode,
+    digit_mode: DigitMode,
+    rounding: RoundingMode,
+    digits: i32,
+    grouping: bool,
+}
+
+impl Default for BigFloatFormat {
+    fn default() -> Self {
+        BigFloatFormat {
+            exponent_mode: ExponentMode::Fixed,
+            digit_mode: DigitMode::FixedDecimals,
+            rounding: RoundingMode::HalfEven,
+            digits: 2,
+            grouping: true,
+        }
+    }
+}
+
+impl BigFloatFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn exponent_mode(mut self, mode: ExponentMode) -> Self {
+        self.exponent_mode = mode;
+        self
+    }
+
+    pub fn digit_mode(mut self, mode: DigitMode) -> Self {
+        self.digit_mode = mode;
+        self
+    }
+
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Digits to show (decimal places or significant digits, depending on
+    /// `digit_mode`), or a negative number to show the value exactly as produced,
+    /// untruncated
+    pub fn digits(mut self, digits: i32) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    pub fn grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Render `f` to a string following this builder's settings
+    pub fn format(&self, f: &BigFloat) -> anyhow::Result<String> {
+        let s = bigfloat_to_string(f)?;
+        let is_negative = s.starts_with('-');
+        let (int_part, frac_part) = split_decimal(&s);
+
+        // `Scientific` always renders in scientific notation, even at exponent 0 (e.g.
+        // `3.5` -> `3.50e0`); only `Auto` gates the choice on how far the exponent is
+        // from zero
+        let use_scientific = match self.exponent_mode {
+            ExponentMode::Scientific => true,
+            ExponentMode::Fixed => false,
+            ExponentMode::Auto { threshold } => {
+                decimal_exponent(int_part, frac_part).abs() > threshold
+            }
+        };
+
+        let body = if use_scientific {
+            format_scientific(int_part, frac_part, self.digits, self.rounding, is_negative)
+        } else {
+            format_fixed(
+                int_part,
+                frac_part,
+                self.digits,
+                self.digit_mode,
+                self.rounding,
+                self.grouping,
+                is_negative,
+            )
+        };
+
+        Ok(if is_negative { format!("-{body}") } else { body })
+    }
+}
+
 #[inline]
 /// Convert `BigFloat` to a formatted string with 2 dp
 pub fn bigfloat_fmt(f: &BigFloat) -> anyhow::Result<String> {
-    internal_bigfloat_fmt(f, 2, None)
+    BigFloatFormat::new().format(f)
 }
 
 #[inline]
 /// Convert `BigFloat` to a formatted string with specified dp
 pub fn bigfloat_fmt_dp(f: &BigFloat, decimal_places: i32) -> anyhow::Result<String> {
-    internal_bigfloat_fmt(f, decimal_places, None)
+    BigFloatFormat::new().digits(decimal_places).format(f)
+}
+
+#[inline]
+/// Convert `BigFloat` to a formatted string with specified dp, using an explicit
+/// `RoundingMode` instead of the `HalfEven` default
+pub fn bigfloat_fmt_dp_with(
+    f: &BigFloat,
+    decimal_places: i32,
+    mode: RoundingMode,
+) -> anyhow::Result<String> {
+    BigFloatFormat::new()
+        .digits(decimal_places)
+        .rounding(mode)
+        .format(f)
 }
 
 #[inline]
-/// Convert `BigFloat` to a formatted string, ignoring significant digits
+/// Convert `BigFloat` to a formatted string, showing `decimal_places` digits after
+/// skipping a leading run of `significant`
 pub fn bigfloat_fmt_sig(
     f: &BigFloat,
     decimal_places: i32,
     significant: char,
 ) -> anyhow::Result<String> {
-    internal_bigfloat_fmt(f, decimal_places, Some(significant))
+    BigFloatFormat::new()
+        .digit_mode(DigitMode::SignificantDigits {
+            skip_char: significant,
+        })
+        .digits(decimal_places)
+        .format(f)
 }
 
-/// Internal helper to format a `BigFloat` to a string
-fn internal_bigfloat_fmt(
+/// Convert `BigFloat` to a formatted string following a locale/style descriptor instead
+/// of the US-only hard-coded `','`/`'.'` of `bigfloat_fmt*`, e.g. `NumberFormat::european()`
+/// for `1.234.567,89` or `NumberFormat::indian()` for lakh/crore grouping. Always rounds
+/// `decimal_places` half to even, same as `bigfloat_fmt_dp`
+pub fn bigfloat_fmt_styled(
     f: &BigFloat,
     decimal_places: i32,
-    significant: Option<char>,
+    style: &NumberFormat,
 ) -> anyhow::Result<String> {
     let s = bigfloat_to_string(f)?;
+    let is_negative = s.starts_with('-');
+    let (int_part, frac_part) = split_decimal(&s);
+
+    let (int_result, frac_result) = if decimal_places < 0 {
+        (int_part.to_string(), frac_part.to_string())
+    } else {
+        round_decimal(
+            int_part,
+            frac_part,
+            decimal_places as usize,
+            RoundingMode::HalfEven,
+            is_negative,
+        )
+    };
+
+    let int_result = if int_result.len() < style.minimum_integer_digits {
+        format!(
+            "{}{int_result}",
+            "0".repeat(style.minimum_integer_digits - int_result.len())
+        )
+    } else {
+        int_result
+    };
+    let int_result = group_digits(&int_result, style.grouping_separator, style.grouping_pattern);
+
+    let body = if frac_result.is_empty() {
+        int_result
+    } else {
+        format!("{int_result}{}{frac_result}", style.decimal_point)
+    };
+
+    Ok(if is_negative { format!("-{body}") } else { body })
+}
+
+/// Adapter that implements `std::fmt::Display`/`LowerExp` for a `BigFloat` (the orphan
+/// rule rules out implementing them on `BigFloat` itself, since both the trait and the
+/// type are foreign). Wrap a reference in this to drop it straight into `format!`/
+/// `write!`/`println!`: `{:.2}` renders exactly two fractional digits (rounded half to
+/// even, the same path as `bigfloat_fmt_dp`, not clipped to width), `{:+}` forces a
+/// leading sign, and fill/width/align work for column alignment
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayBigFloat<'a>(pub &'a BigFloat);
+
+/// Default fractional digits shown when a format spec gives no explicit precision,
+/// matching `bigfloat_fmt`'s default
+const DISPLAY_DEFAULT_DECIMAL_PLACES: i32 = 2;
+
+impl std::fmt::Display for DisplayBigFloat<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let decimal_places = f
+            .precision()
+            .map_or(DISPLAY_DEFAULT_DECIMAL_PLACES, |p| p as i32);
+        let body = bigfloat_fmt_dp(self.0, decimal_places).map_err(|_| std::fmt::Error)?;
+        write_signed_padded(f, &body)
+    }
+}
+
+impl std::fmt::LowerExp for DisplayBigFloat<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let decimal_places = f
+            .precision()
+            .map_or(DISPLAY_DEFAULT_DECIMAL_PLACES, |p| p as i32);
+        let body = BigFloatFormat::new()
+            .exponent_mode(ExponentMode::Scientific)
+            .digits(decimal_places)
+            .format(self.0)
+            .map_err(|_| std::fmt::Error)?;
+        write_signed_padded(f, &body)
+    }
+}
+
+/// Apply a format spec's `{:+}` sign flag and fill/width/align to an already-rendered
+/// numeric string (which carries its own `-` prefix when negative, but never a `+`).
+/// `Formatter::pad` isn't used here since it treats its `precision` as a string-length
+/// truncation, which would clip digits rather than leaving the already-rounded body
+/// alone
+fn write_signed_padded(f: &mut std::fmt::Formatter<'_>, body: &str) -> std::fmt::Result {
+    let signed = if f.sign_plus() && !body.starts_with('-') {
+        format!("+{body}")
+    } else {
+        body.to_string()
+    };
 
-    if !s.contains('.') {
-        // no decimal point, return as is
-        return Ok(s);
+    let len = signed.chars().count();
+    match f.width() {
+        Some(width) if width > len => {
+            let pad = width - len;
+            let fill = f.fill();
+            match f.align() {
+                Some(std::fmt::Alignment::Left) => {
+                    write!(f, "{signed}{}", fill.to_string().repeat(pad))
+                }
+                Some(std::fmt::Alignment::Center) => {
+                    let left = pad / 2;
+                    let right = pad - left;
+                    write!(
+                        f,
+                        "{}{signed}{}",
+                        fill.to_string().repeat(left),
+                        fill.to_string().repeat(right)
+                    )
+                }
+                _ => write!(f, "{}{signed}", fill.to_string().repeat(pad)),
+            }
+        }
+        _ => write!(f, "{signed}"),
     }
+}
 
-    // split the string into left and right of the decimal point
-    let parts = s
-        .split_once('.')
-        .ok_or_else(|| anyhow!("Failed to split string"))?;
-    let left = parts.0;
-    let mut right = parts.1;
+/// How many digits after the decimal point to keep, given the requested `digits` and
+/// `digit_mode`
+fn effective_keep(frac_part: &str, digits: i32, digit_mode: DigitMode) -> usize {
+    let digits = digits.max(0) as usize;
+    match digit_mode {
+        DigitMode::FixedDecimals => digits,
+        DigitMode::SignificantDigits { skip_char } => {
+            let skip = frac_part
+                .bytes()
+                .take_while(|&b| b == skip_char as u8)
+                .count();
+            skip + digits
+        }
+    }
+}
 
-    // === add commas to the left side of the decimal point ===
-    let mut buff = String::with_capacity(left.len() + left.len() / 3 + 5);
+/// The power-of-ten exponent of the first significant digit, e.g. `123.456` -> 2,
+/// `0.00045` -> -4. Zero if every digit is zero
+fn decimal_exponent(int_part: &str, frac_part: &str) -> i32 {
+    let concatenated: String = int_part.chars().chain(frac_part.chars()).collect();
+    match concatenated.bytes().position(|b| b != b'0') {
+        Some(idx) => int_part.len() as i32 - 1 - idx as i32,
+        None => 0,
+    }
+}
+
+/// Insert comma grouping every three digits, e.g. `"1234567"` -> `"1,234,567"`
+fn group_thousands(int_part: &str) -> String {
+    group_digits(int_part, ',', GroupingPattern::Fixed3)
+}
+
+/// Cluster `int_part`'s digits per `pattern`, inserting `separator` between groups.
+/// `Fixed3` groups every three digits throughout (US/SI style); `Indian` groups the
+/// least-significant three digits, then every two thereafter (lakh/crore, e.g.
+/// `"1234567"` -> `"12,34,567"`), which a fixed stride can't express
+fn group_digits(int_part: &str, separator: char, pattern: GroupingPattern) -> String {
+    let mut buff = String::with_capacity(int_part.len() + int_part.len() / 2 + 1);
     let mut count = 0;
-    for c in left.chars().rev() {
-        if count == 3 {
-            buff.push(',');
+    let mut first_group = true;
+    for c in int_part.chars().rev() {
+        let group_size = match pattern {
+            GroupingPattern::Fixed3 => 3,
+            GroupingPattern::Indian if first_group => 3,
+            GroupingPattern::Indian => 2,
+        };
+        if count == group_size {
+            buff.push(separator);
             count = 0;
+            first_group = false;
         }
         buff.push(c);
         count += 1;
     }
+    buff.chars().rev().collect()
+}
 
-    // reverse the string
-    let mut output = buff.chars().rev().collect::<String>();
-
-    // === truncate the decimal places ===
-    let mut padding: usize = 0;
-    if decimal_places > -1 {
-        if let Some(ch) = significant {
-            // truncate after the first non-ch character
-            for (i, c) in right.chars().enumerate() {
-                if c != ch {
-                    // i is the position of the first non-ch character
-                    #[allow(clippy::cast_sign_loss)]
-                    let take = i + decimal_places as usize;
-                    if take > right.len() {
-                        // not enough characters, add zeros
-                        padding = take - right.len();
-                    } else {
-                        right = &right[..take];
-                    }
-                    break;
-                }
+/// Round a decimal string (given as separate, sign-stripped integer and fractional
+/// digit strings) to `keep` digits after the decimal point, given the sign of the
+/// original value (needed for `Ceil`/`Floor`, which round toward an absolute direction
+/// rather than toward/away from zero). The carry from rounding up propagates
+/// right-to-left through the kept digits and, on overflow, into the integer part (e.g.
+/// `9.99 -> 10.00`)
+fn round_decimal(
+    int_part: &str,
+    frac_part: &str,
+    keep: usize,
+    rounding: RoundingMode,
+    negative: bool,
+) -> (String, String) {
+    let mut frac_digits: Vec<u8> = frac_part.bytes().map(|b| b - b'0').collect();
+    while frac_digits.len() < keep {
+        frac_digits.push(0);
+    }
+    let dropped = frac_digits.split_off(keep);
+    let any_dropped_nonzero = dropped.iter().any(|&d| d != 0);
+
+    let mut digits: Vec<u8> = int_part
+        .bytes()
+        .map(|b| b - b'0')
+        .chain(frac_digits)
+        .collect();
+
+    let round_up = match rounding {
+        RoundingMode::TowardZero => false,
+        RoundingMode::Ceil => !negative && any_dropped_nonzero,
+        RoundingMode::Floor => negative && any_dropped_nonzero,
+        RoundingMode::HalfUp => matches!(dropped.first(), Some(&first) if first >= 5),
+        RoundingMode::HalfDown => match dropped.split_first() {
+            Some((&first, rest)) => first > 5 || (first == 5 && rest.iter().any(|&d| d != 0)),
+            None => false,
+        },
+        RoundingMode::HalfEven => match dropped.split_first() {
+            Some((&first, rest)) if first > 5 || (first == 5 && rest.iter().any(|&d| d != 0)) => {
+                true
             }
-        } else {
-            // truncate unconditionally
-            #[allow(clippy::cast_sign_loss)]
-            match parts.1.len().cmp(&(decimal_places as usize)) {
-                std::cmp::Ordering::Greater => {
-                    // truncate the decimal places
-                    right = &parts.1[..decimal_places as usize];
-                }
-                std::cmp::Ordering::Less => {
-                    // not enough decimal places, add zeros
-                    padding = (decimal_places - parts.1.len() as i32) as usize;
-                }
-                std::cmp::Ordering::Equal => {
-                    // do nothing, the length is exactly as needed
-                }
+            Some((&first, _)) if first == 5 => digits.last().copied().unwrap_or(0) % 2 == 1,
+            _ => false,
+        },
+    };
+
+    if round_up {
+        let mut carry = 1u8;
+        for d in digits.iter_mut().rev() {
+            let sum = *d + carry;
+            *d = sum % 10;
+            carry = sum / 10;
+            if carry == 0 {
+                break;
             }
         }
+        if carry > 0 {
+            digits.insert(0, carry);
+        }
+    }
+
+    let new_int_len = digits.len() - keep;
+    let int_result: String = digits[..new_int_len]
+        .iter()
+        .map(|d| (d + b'0') as char)
+        .collect();
+    let frac_result: String = digits[new_int_len..]
+        .iter()
+        .map(|d| (d + b'0') as char)
+        .collect();
+
+    (int_result, frac_result)
+}
+
+/// Render in plain decimal notation, rounding to the requested digit count and
+/// reapplying comma grouping to the (possibly carry-lengthened) integer part
+fn format_fixed(
+    int_part: &str,
+    frac_part: &str,
+    digits: i32,
+    digit_mode: DigitMode,
+    rounding: RoundingMode,
+    grouping: bool,
+    negative: bool,
+) -> String {
+    let (int_result, frac_result) = if digits < 0 {
+        (int_part.to_string(), frac_part.to_string())
+    } else {
+        let keep = effective_keep(frac_part, digits, digit_mode);
+        round_decimal(int_part, frac_part, keep, rounding, negative)
+    };
+
+    let int_result = if grouping {
+        group_thousands(&int_result)
+    } else {
+        int_result
+    };
+
+    if frac_result.is_empty() {
+        int_result
+    } else {
+        format!("{int_result}.{frac_result}")
+    }
+}
+
+/// Render in scientific notation, e.g. `1.2345e3`, rounding the mantissa to `digits`
+/// digits after its leading digit
+fn format_scientific(
+    int_part: &str,
+    frac_part: &str,
+    digits: i32,
+    rounding: RoundingMode,
+    negative: bool,
+) -> String {
+    let exponent = decimal_exponent(int_part, frac_part);
+    let concatenated: String = int_part.chars().chain(frac_part.chars()).collect();
+
+    let Some(first_nonzero) = concatenated.bytes().position(|b| b != b'0') else {
+        let zeros = if digits > 0 {
+            format!(".{}", "0".repeat(digits as usize))
+        } else {
+            String::new()
+        };
+        return format!("0{zeros}e0");
+    };
+
+    let mantissa_digits = &concatenated[first_nonzero..];
+    let (lead, rest) = mantissa_digits.split_at(1);
+    let keep = digits.max(0) as usize;
+
+    let (lead_result, rest_result) = round_decimal(lead, rest, keep, rounding, negative);
+
+    // rounding up can carry the single leading digit into two digits (e.g. "9.99.."
+    // -> "10"), which bumps the exponent and shifts a digit back into the mantissa
+    let (lead_result, rest_result, exponent) = if lead_result.len() > 1 {
+        let mut combined = lead_result;
+        combined.push_str(&rest_result);
+        let new_lead = combined[..1].to_string();
+        let new_rest = combined[1..combined.len() - 1].to_string();
+        (new_lead, new_rest, exponent + 1)
+    } else {
+        (lead_result, rest_result, exponent)
+    };
+
+    if rest_result.is_empty() {
+        format!("{lead_result}e{exponent}")
+    } else {
+        format!("{lead_result}.{rest_result}e{exponent}")
+    }
+}
+
+/// Parse a decimal literal (`[sign]digits[.digits][(e|E)[sign]digits]`) into the
+/// `BigFloat` nearest its exact value at `binary_digits` precision, ties resolved half
+/// to even. Built from an exact big-integer significand `f` and decimal exponent `e`
+/// (`12.34e56` -> `f = 1234, e = 54`), scaled by `10^e` using `BigFloat` arithmetic at
+/// enough guard precision to hold `f` exactly, and only rounded down to `binary_digits`
+/// as the very last step -- this is what avoids the rounding noise a naive intermediate
+/// `f64` or per-digit scaling would introduce. A missing fractional or exponent part is
+/// treated as zero; an `e` far outside `i32` range underflows to zero or overflows to
+/// the largest representable `BigFloat` rather than erroring
+fn bigfloat_from_decimal_str(s: &str, binary_digits: usize) -> BigFloat {
+    let trimmed = s.trim();
+    let (negative, unsigned) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+
+    let (mantissa, exponent_str) = match unsigned.split_once(['e', 'E']) {
+        Some((m, e)) => (m, e),
+        None => (unsigned, ""),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let digits: String = int_part.chars().chain(frac_part.chars()).collect();
+    assert!(
+        !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()),
+        "Invalid decimal literal: '{s}'"
+    );
+
+    let exponent: i64 = if exponent_str.is_empty() {
+        0
+    } else {
+        exponent_str
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid exponent in decimal literal: '{s}'"))
+    };
+    #[allow(clippy::cast_possible_wrap)]
+    let total_exponent = exponent - frac_part.len() as i64;
+
+    // precision needed to hold the big-integer significand `f` exactly: ~3.32 bits per
+    // decimal digit, plus guard bits for the power-of-ten scaling that follows
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    let exact_digits = (digits.len() as f64 * 3.33).ceil() as usize;
+    let guard_digits = binary_digits.max(exact_digits) + GUARD_BITS;
+
+    let ten = BigFloat::from_u32(10, guard_digits);
+    let mut significand = BigFloat::from_u32(0, guard_digits);
+    for c in digits.chars() {
+        let digit = BigFloat::from_u32(c.to_digit(10).unwrap(), guard_digits);
+        significand = significand
+            .mul(&ten, guard_digits, ROUNDING)
+            .add(&digit, guard_digits, ROUNDING);
+    }
+
+    // lower bound is `i32::MIN + 1` so that negating it below cannot overflow
+    let exp_i32 = i32::try_from(total_exponent.clamp(i64::from(i32::MIN) + 1, i64::from(i32::MAX)))
+        .unwrap_or(0);
+    let scaled = if exp_i32 >= 0 {
+        let power = ten.powi(exp_i32, guard_digits, ROUNDING);
+        significand.mul(&power, guard_digits, ROUNDING)
+    } else {
+        let power = ten.powi(-exp_i32, guard_digits, ROUNDING);
+        significand.div(&power, guard_digits, ROUNDING)
+    };
+
+    // round down to the requested working precision only at this final step
+    let result = scaled.div(&BigFloat::from_u32(1, guard_digits), binary_digits, ROUNDING);
+
+    if negative {
+        result.mul(
+            &BigFloat::from_i32(-1, binary_digits),
+            binary_digits,
+            ROUNDING,
+        )
+    } else {
+        result
     }
+}
+
+/// Check that `s` looks like a decimal number `bigfloat_from_decimal_str` can parse: an
+/// optional sign, a non-empty mantissa with at most one decimal point and at least one
+/// digit, and an optional exponent marker (`e`/`E`) with its own optional sign and a
+/// non-empty run of digits
+fn validate_decimal_number(s: &str) -> anyhow::Result<()> {
+    let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((m, e)) => (m, Some(e)),
+        None => (unsigned, None),
+    };
 
-    if !right.is_empty() || padding > 0 {
-        output.push('.');
-        output.push_str(right);
-        if padding > 0 {
-            // add padding zeros, if required
-            output.push_str(&"0".repeat(padding));
+    if mantissa.is_empty() || !mantissa.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return Err(anyhow!("invalid mantissa"));
+    }
+    if mantissa.matches('.').count() > 1 {
+        return Err(anyhow!("mantissa has more than one decimal point"));
+    }
+    if !mantissa.chars().any(|c| c.is_ascii_digit()) {
+        return Err(anyhow!("mantissa has no digits"));
+    }
+
+    if let Some(exponent) = exponent {
+        let exponent_digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        if exponent_digits.is_empty() || !exponent_digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(anyhow!("invalid exponent"));
         }
     }
 
-    Ok(output)
+    Ok(())
+}
+
+/// Split a non-scientific-notation decimal string produced by `bigfloat_to_string` into
+/// its (sign-stripped integer part, fractional part) pieces
+fn split_decimal(s: &str) -> (&str, &str) {
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    unsigned.split_once('.').unwrap_or((unsigned, ""))
+}
+
+/// Round a plain (non-scientific-notation) decimal string to `sig_digits` significant
+/// digits, half to even, returning a plain decimal string. Reuses `round_decimal` by
+/// treating the digits from the first nonzero onward as a one-digit integer part plus a
+/// fractional tail, then reinserts the decimal point at the right place, accounting for
+/// the kept digits growing by one on carry (e.g. `999.6` -> `1000`)
+fn round_to_significant_digits(s: &str, sig_digits: usize) -> String {
+    let is_negative = s.starts_with('-');
+    let (int_part, frac_part) = split_decimal(s);
+    let concatenated: String = int_part.chars().chain(frac_part.chars()).collect();
+
+    let Some(first_nonzero) = concatenated.bytes().position(|b| b != b'0') else {
+        return "0".to_string();
+    };
+
+    let mantissa_digits = &concatenated[first_nonzero..];
+    let (lead, rest) = mantissa_digits.split_at(1);
+    let keep = sig_digits.saturating_sub(1);
+    let (lead_result, rest_result) =
+        round_decimal(lead, rest, keep, RoundingMode::HalfEven, is_negative);
+
+    let extra_digits = lead_result.len() - 1;
+    let point_offset = int_part.len() as isize - first_nonzero as isize + extra_digits as isize;
+    let combined = format!("{lead_result}{rest_result}");
+
+    let body = if point_offset <= 0 {
+        format!("0.{}{combined}", "0".repeat((-point_offset) as usize))
+    } else if point_offset as usize >= combined.len() {
+        format!("{combined}{}", "0".repeat(point_offset as usize - combined.len()))
+    } else {
+        let (int_digits, frac_digits) = combined.split_at(point_offset as usize);
+        format!("{int_digits}.{frac_digits}")
+    };
+
+    if is_negative { format!("-{body}") } else { body }
+}
+
+/// Report how many leading decimal places (digits after the decimal point) two
+/// `BigFloat`s agree on. Returns 0 if the integer parts differ, since the two values
+/// then disagree before the decimal point is even reached. Two identical values agree on
+/// every place and report `usize::MAX`; where one fractional part is shorter, it is
+/// compared as if padded with trailing zeros
+pub fn matching_decimal_places(a: &BigFloat, b: &BigFloat) -> usize {
+    let Ok(sa) = bigfloat_to_string(a) else {
+        return 0;
+    };
+    let Ok(sb) = bigfloat_to_string(b) else {
+        return 0;
+    };
+    if sa == sb {
+        return usize::MAX;
+    }
+    let (int_a, frac_a) = split_decimal(&sa);
+    let (int_b, frac_b) = split_decimal(&sb);
+
+    if int_a != int_b {
+        return 0;
+    }
+    let len = frac_a.len().max(frac_b.len());
+    let pad = std::iter::repeat('0');
+    frac_a
+        .chars()
+        .chain(pad.clone())
+        .take(len)
+        .zip(frac_b.chars().chain(pad).take(len))
+        .take_while(|(x, y)| x == y)
+        .count()
+}
+
+/// Report how many leading significant digits two `BigFloat`s agree on, ignoring
+/// magnitude (leading zeros are skipped before comparing), so e.g. `0.000123` and
+/// `0.0001234` are compared starting from the first `1`
+pub fn matching_significant_digits(a: &BigFloat, b: &BigFloat) -> usize {
+    let Ok(sa) = bigfloat_to_string(a) else {
+        return 0;
+    };
+    let Ok(sb) = bigfloat_to_string(b) else {
+        return 0;
+    };
+    let (int_a, frac_a) = split_decimal(&sa);
+    let (int_b, frac_b) = split_decimal(&sb);
+
+    let digits_a: String = int_a.chars().chain(frac_a.chars()).collect();
+    let digits_b: String = int_b.chars().chain(frac_b.chars()).collect();
+    let digits_a = digits_a.trim_start_matches('0');
+    let digits_b = digits_b.trim_start_matches('0');
+
+    digits_a
+        .chars()
+        .zip(digits_b.chars())
+        .take_while(|(x, y)| x == y)
+        .count()
 }
 
 /// Convert `BigFloat` to a string
@@ -651,6 +1984,220 @@ pub fn bigfloat_to_string(f: &BigFloat) -> anyhow::Result<String> {
     Ok(if is_negative { format!("-{}", result) } else { result })
 }
 
+/// A `BigFloat` extended with the IEEE values plain `BigFloat` arithmetic has no
+/// crate-level vocabulary for: signed infinity and NaN. Relativity math hits these at
+/// its own singularities — a velocity arbitrarily close to `c` drives the Lorentz factor
+/// to infinity — so results that can diverge are expressed as this instead of a bare
+/// `BigFloat` that would otherwise have to saturate, panic, or lie. `Finite` carries the
+/// ordinary value (including a signed zero, since `BigFloat` itself already
+/// distinguishes `+0`/`-0`)
+#[derive(Debug, Clone)]
+pub enum ExtendedBigFloat {
+    /// An ordinary finite value, including signed zero
+    Finite(BigFloat),
+    /// Positive infinity
+    PosInf,
+    /// Negative infinity
+    NegInf,
+    /// Not a number: the result of an indeterminate operation (e.g. `inf - inf`, `0/0`)
+    NaN,
+}
+
+impl ExtendedBigFloat {
+    fn is_zero(value: &BigFloat, precision: usize) -> bool {
+        let zero = BigFloat::from_i32(0, precision);
+        value.ge(&zero) && zero.ge(value)
+    }
+
+    fn is_negative(value: &BigFloat, precision: usize) -> bool {
+        !value.ge(&BigFloat::from_i32(0, precision))
+    }
+
+    /// Sign of a non-NaN `ExtendedBigFloat`, used when the result is itself infinite and
+    /// only the sign of the operands (not their magnitude) decides the outcome
+    fn is_negative_signed(value: &Self, precision: usize) -> bool {
+        match value {
+            ExtendedBigFloat::Finite(x) => Self::is_negative(x, precision),
+            ExtendedBigFloat::NegInf => true,
+            ExtendedBigFloat::PosInf | ExtendedBigFloat::NaN => false,
+        }
+    }
+
+    /// `a + b`, propagating per IEEE rules: any `NaN` poisons the result, and opposing
+    /// infinities (`inf + -inf`) are indeterminate
+    pub fn add(&self, other: &Self, precision: usize, rounding: AstroRoundingMode) -> Self {
+        match (self, other) {
+            (ExtendedBigFloat::NaN, _) | (_, ExtendedBigFloat::NaN) => ExtendedBigFloat::NaN,
+            (ExtendedBigFloat::Finite(a), ExtendedBigFloat::Finite(b)) => {
+                ExtendedBigFloat::Finite(a.add(b, precision, rounding))
+            }
+            (ExtendedBigFloat::PosInf, ExtendedBigFloat::NegInf)
+            | (ExtendedBigFloat::NegInf, ExtendedBigFloat::PosInf) => ExtendedBigFloat::NaN,
+            (ExtendedBigFloat::PosInf, _) | (_, ExtendedBigFloat::PosInf) => {
+                ExtendedBigFloat::PosInf
+            }
+            (ExtendedBigFloat::NegInf, _) | (_, ExtendedBigFloat::NegInf) => {
+                ExtendedBigFloat::NegInf
+            }
+        }
+    }
+
+    /// `a - b`, propagating per IEEE rules: `inf - inf` and `-inf - -inf` are
+    /// indeterminate
+    pub fn sub(&self, other: &Self, precision: usize, rounding: AstroRoundingMode) -> Self {
+        match (self, other) {
+            (ExtendedBigFloat::NaN, _) | (_, ExtendedBigFloat::NaN) => ExtendedBigFloat::NaN,
+            (ExtendedBigFloat::Finite(a), ExtendedBigFloat::Finite(b)) => {
+                ExtendedBigFloat::Finite(a.sub(b, precision, rounding))
+            }
+            (ExtendedBigFloat::PosInf, ExtendedBigFloat::PosInf)
+            | (ExtendedBigFloat::NegInf, ExtendedBigFloat::NegInf) => ExtendedBigFloat::NaN,
+            (ExtendedBigFloat::PosInf, _) | (_, ExtendedBigFloat::NegInf) => {
+                ExtendedBigFloat::PosInf
+            }
+            (ExtendedBigFloat::NegInf, _) | (_, ExtendedBigFloat::PosInf) => {
+                ExtendedBigFloat::NegInf
+            }
+        }
+    }
+
+    /// `a * b`, propagating per IEEE rules: `0 * inf` is indeterminate, otherwise an
+    /// infinite operand gives an infinite result whose sign is the usual product of signs
+    pub fn mul(&self, other: &Self, precision: usize, rounding: AstroRoundingMode) -> Self {
+        match (self, other) {
+            (ExtendedBigFloat::NaN, _) | (_, ExtendedBigFloat::NaN) => ExtendedBigFloat::NaN,
+            (ExtendedBigFloat::Finite(a), ExtendedBigFloat::Finite(b)) => {
+                ExtendedBigFloat::Finite(a.mul(b, precision, rounding))
+            }
+            (ExtendedBigFloat::Finite(v), _) | (_, ExtendedBigFloat::Finite(v))
+                if Self::is_zero(v, precision) =>
+            {
+                ExtendedBigFloat::NaN
+            }
+            (a, b) => {
+                if Self::is_negative_signed(a, precision) != Self::is_negative_signed(b, precision)
+                {
+                    ExtendedBigFloat::NegInf
+                } else {
+                    ExtendedBigFloat::PosInf
+                }
+            }
+        }
+    }
+
+    /// `a / b`, propagating per IEEE rules: `1/0 = +inf`, `-1/0 = -inf`, `0/0` and
+    /// `inf/inf` are indeterminate, and a finite value divided by an infinity is `0`
+    pub fn div(&self, other: &Self, precision: usize, rounding: AstroRoundingMode) -> Self {
+        match (self, other) {
+            (ExtendedBigFloat::NaN, _) | (_, ExtendedBigFloat::NaN) => ExtendedBigFloat::NaN,
+            (ExtendedBigFloat::PosInf | ExtendedBigFloat::NegInf, ExtendedBigFloat::PosInf | ExtendedBigFloat::NegInf) => {
+                ExtendedBigFloat::NaN
+            }
+            (ExtendedBigFloat::Finite(_), ExtendedBigFloat::PosInf | ExtendedBigFloat::NegInf) => {
+                ExtendedBigFloat::Finite(BigFloat::from_i32(0, precision))
+            }
+            (ExtendedBigFloat::Finite(a), ExtendedBigFloat::Finite(b))
+                if Self::is_zero(b, precision) =>
+            {
+                if Self::is_zero(a, precision) {
+                    ExtendedBigFloat::NaN
+                } else if Self::is_negative(a, precision) {
+                    ExtendedBigFloat::NegInf
+                } else {
+                    ExtendedBigFloat::PosInf
+                }
+            }
+            (ExtendedBigFloat::Finite(a), ExtendedBigFloat::Finite(b)) => {
+                ExtendedBigFloat::Finite(a.div(b, precision, rounding))
+            }
+            (a, b) => {
+                if Self::is_negative_signed(a, precision) != Self::is_negative_signed(b, precision)
+                {
+                    ExtendedBigFloat::NegInf
+                } else {
+                    ExtendedBigFloat::PosInf
+                }
+            }
+        }
+    }
+}
+
+/// Render an `ExtendedBigFloat`: finite values follow `bigfloat_to_string`, and
+/// infinities/NaN render as the familiar `"inf"`/`"-inf"`/`"nan"` literals
+pub fn extended_bigfloat_to_string(v: &ExtendedBigFloat) -> anyhow::Result<String> {
+    match v {
+        ExtendedBigFloat::Finite(f) => bigfloat_to_string(f),
+        ExtendedBigFloat::PosInf => Ok("inf".to_string()),
+        ExtendedBigFloat::NegInf => Ok("-inf".to_string()),
+        ExtendedBigFloat::NaN => Ok("nan".to_string()),
+    }
+}
+
+/// `bigfloat_fmt_dp`, extended to render `"inf"`/`"-inf"`/`"nan"` for non-finite values
+pub fn extended_bigfloat_fmt_dp(
+    v: &ExtendedBigFloat,
+    decimal_places: i32,
+) -> anyhow::Result<String> {
+    match v {
+        ExtendedBigFloat::Finite(f) => bigfloat_fmt_dp(f, decimal_places),
+        ExtendedBigFloat::PosInf => Ok("inf".to_string()),
+        ExtendedBigFloat::NegInf => Ok("-inf".to_string()),
+        ExtendedBigFloat::NaN => Ok("nan".to_string()),
+    }
+}
+
+/// `bigfloat_fmt`, extended to render `"inf"`/`"-inf"`/`"nan"` for non-finite values
+#[inline]
+pub fn extended_bigfloat_fmt(v: &ExtendedBigFloat) -> anyhow::Result<String> {
+    extended_bigfloat_fmt_dp(v, 2)
+}
+
+/// `bigfloat_fmt_sig`, extended to render `"inf"`/`"-inf"`/`"nan"` for non-finite values
+pub fn extended_bigfloat_fmt_sig(
+    v: &ExtendedBigFloat,
+    decimal_places: i32,
+    significant: char,
+) -> anyhow::Result<String> {
+    match v {
+        ExtendedBigFloat::Finite(f) => bigfloat_fmt_sig(f, decimal_places, significant),
+        ExtendedBigFloat::PosInf => Ok("inf".to_string()),
+        ExtendedBigFloat::NegInf => Ok("-inf".to_string()),
+        ExtendedBigFloat::NaN => Ok("nan".to_string()),
+    }
+}
+
+/// Iterator built by `Relativity::bigfloat_range`: see that method's doc comment for how
+/// stable it is (and isn't). `decimal_places` is `step`'s own fractional-digit count, a
+/// width at which every emitted value formats stably (e.g. via
+/// `bigfloat_fmt_dp(value, range.decimal_places)`)
+pub struct BigFloatRange {
+    current: BigFloat,
+    step: BigFloat,
+    remaining: usize,
+    binary_digits: usize,
+    pub decimal_places: i32,
+}
+
+impl Iterator for BigFloatRange {
+    type Item = BigFloat;
+
+    fn next(&mut self) -> Option<BigFloat> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let value = self.current.clone();
+        self.current = self.current.add(&self.step, self.binary_digits, ROUNDING);
+        self.remaining -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for BigFloatRange {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -689,10 +2236,13 @@ mod tests {
         let result = bigfloat_to_string(&f).unwrap();
         assert_eq!(result, "0.0000123456");
 
-        // Exponent 0
+        // Exponent 0: the correctly-rounded parse in `bigfloat_from_decimal_str` keeps
+        // the literal's own digits exact instead of inheriting an intermediate f64's
+        // rounding error (this used to print "1.2345600000000000001")
         let f = Relativity::bigfloat_from_str("1.23456e0");
         let result = bigfloat_to_string(&f).unwrap();
-        assert_eq!(result, "1.2345600000000000001");  // Adjusted for actual precision
+        assert!(result.starts_with("1.23456"));
+        assert_ne!(result, "1.2345600000000000001");
     }
 
     #[test]
@@ -740,17 +2290,21 @@ mod tests {
         let rel = Relativity::new(100);
         let f = rel.bigfloat_from_f64(1234567.89);
         
-        // Test default 2 decimal places - based on actual behavior
+        // Test default 2 decimal places - 1234567.89 is stored as ...889999999897...,
+        // so correctly rounding (rather than truncating) the dropped "9999..." tail
+        // rounds the last kept digit up
         let result = bigfloat_fmt(&f).unwrap();
-        assert_eq!(result, "1,234,567.88");
-        
-        // Test specific decimal places
+        assert_eq!(result, "1,234,567.89");
+
+        // Test specific decimal places - the dropped 4th digit is also a 9, so the
+        // carry propagates through the kept "889" to "890"
         let result = bigfloat_fmt_dp(&f, 3).unwrap();
-        assert_eq!(result, "1,234,567.889");
-        
-        // Test with no decimal places
+        assert_eq!(result, "1,234,567.890");
+
+        // Test with no decimal places - the first dropped digit (8) rounds the integer
+        // part up
         let result = bigfloat_fmt_dp(&f, 0).unwrap();
-        assert_eq!(result, "1,234,567");
+        assert_eq!(result, "1,234,568");
         
         // Test significant formatting - let's see what this produces
         let f2 = Relativity::bigfloat_from_str("0.0001234");
@@ -763,10 +2317,12 @@ mod tests {
     fn test_internal_bigfloat_fmt_commas() {
         let rel = Relativity::new(100);
         
-        // Test comma formatting for large numbers - based on actual floating point precision
+        // Test comma formatting for large numbers - 1234567890.123 is stored as
+        // ...12299990653..., and round-half-to-even rounds the dropped "9999..." tail
+        // up to "123"
         let f = rel.bigfloat_from_f64(1234567890.123);
         let result = bigfloat_fmt_dp(&f, 3).unwrap();
-        assert_eq!(result, "1,234,567,890.122");  // Adjusted for actual precision
+        assert_eq!(result, "1,234,567,890.123");
         
         // Test no commas for smaller numbers - use more reliable input
         let f = rel.bigfloat_from_f64(123.456);
@@ -817,4 +2373,110 @@ mod tests {
         let result = bigfloat_to_string(&f).unwrap();
         assert_eq!(result, "-0.50");
     }
+
+    #[test]
+    fn test_matching_decimal_places() {
+        let a = Relativity::bigfloat_from_str("1.234567890");
+        let b = Relativity::bigfloat_from_str("1.234567000");
+        assert_eq!(matching_decimal_places(&a, &b), 6);
+
+        let a = Relativity::bigfloat_from_str("1.5");
+        let b = Relativity::bigfloat_from_str("2.5");
+        assert_eq!(matching_decimal_places(&a, &b), 0);
+    }
+
+    #[test]
+    fn test_matching_significant_digits() {
+        let a = Relativity::bigfloat_from_str("0.0001234567");
+        let b = Relativity::bigfloat_from_str("0.0001234999");
+        assert_eq!(matching_significant_digits(&a, &b), 4);
+    }
+
+    // ===== Wolfram-grade reference-value accuracy tests =====
+    //
+    // These decimal strings are the Wolfram Alpha figures quoted in `trig_tests` in
+    // main.rs, the only values there cross-checked against an independent
+    // arbitrary-precision engine rather than just agreeing with one other
+    // implementation. Pinning against them turns the informal precision comments into
+    // an enforced contract: a regression in the guard-precision hyperbolic
+    // reimplementations shows up as a drop in matching decimal places.
+
+    const WOLFRAM_COSH_23_123: &str = "5510123201.27914431112826508186134202583343799887324341461673934843010234623144853523031970955128411171838132510884403729979648113145226014288075570307221399997571142118913265124076194819770885815330760567987582828025342875949859547942365319652846817322865633214063264303746998352885790";
+    const WOLFRAM_TANH_23_123: &str = "0.999999999999999999983531752491885345637693289166333624661533063277950117780606843868154379765470420474221097526796382622376114576762869195722370750965031740995499127782347779096457666967818156592040017088983957629859064832822774675201193782243108354628049649315400218368536400248812147";
+    const WOLFRAM_ACOSH_23_123: &str = "3.833507070054524960329808488163852198447529786975842072917753791434904025616380825183131332671143153917588576048078983322508406565048388855221452083106084519591345875167533944912748457235050127523652699149653839070507949767515303967800802296561746442538093483563378764079480284106317292";
+
+    #[test]
+    fn test_cosh_matches_wolfram_reference() {
+        let mut rel = Relativity::new(300);
+        let x = Relativity::bigfloat_from_str("23.123");
+        let computed = rel.cosh(&x);
+        let reference = Relativity::bigfloat_from_str(WOLFRAM_COSH_23_123);
+        let places = matching_decimal_places(&computed, &reference);
+        assert!(places >= 250, "cosh(23.123) only matched {places} decimal places");
+    }
+
+    #[test]
+    fn test_tanh_matches_wolfram_reference() {
+        let mut rel = Relativity::new(300);
+        let x = Relativity::bigfloat_from_str("23.123");
+        let computed = rel.tanh(&x);
+        let reference = Relativity::bigfloat_from_str(WOLFRAM_TANH_23_123);
+        let places = matching_decimal_places(&computed, &reference);
+        assert!(places >= 250, "tanh(23.123) only matched {places} decimal places");
+    }
+
+    #[test]
+    fn test_acosh_matches_wolfram_reference() {
+        let mut rel = Relativity::new(300);
+        let x = Relativity::bigfloat_from_str("23.123");
+        let computed = rel.acosh(&x);
+        let reference = Relativity::bigfloat_from_str(WOLFRAM_ACOSH_23_123);
+        let places = matching_decimal_places(&computed, &reference);
+        assert!(places >= 250, "acosh(23.123) only matched {places} decimal places");
+    }
+
+    #[test]
+    fn test_lorentz_factor_matches_cosh_of_rapidity() {
+        // gamma = 1/sqrt(1 - beta^2) and gamma = cosh(rapidity) are two independent
+        // routes to the same value; they must agree to (near) the full working precision
+        let mut rel = Relativity::new(300);
+        let fraction = Relativity::bigfloat_from_str("0.8");
+        let v = rel.velocity_from_c(&fraction);
+
+        let gamma_direct = rel.lorentz_factor(&v);
+        let rapidity = rel.rapidity_from_velocity(&v);
+        let gamma_via_cosh = rel.cosh(&rapidity);
+
+        let places = matching_decimal_places(&gamma_direct, &gamma_via_cosh);
+        assert!(places >= 290, "lorentz factor only matched {places} decimal places");
+    }
+
+    #[test]
+    fn test_rapidity_velocity_round_trip() {
+        let mut rel = Relativity::new(300);
+        let fraction = Relativity::bigfloat_from_str("0.6");
+        let v = rel.velocity_from_c(&fraction);
+
+        let rapidity = rel.rapidity_from_velocity(&v);
+        let round_tripped = rel.velocity_from_rapidity(&rapidity);
+
+        let places = matching_decimal_places(&v, &round_tripped);
+        assert!(places >= 290, "velocity round trip only matched {places} decimal places");
+    }
+
+    #[test]
+    fn test_spacetime_interval_matches_analytic_value() {
+        // a purely timelike separation (0,0) -> (2,0) has invariant interval c * delta_t
+        let mut rel = Relativity::new(300);
+        let interval = rel.spacetime_interval_1d_f64((0.0, 0.0), (2.0, 0.0));
+
+        let c = rel.get_c().clone();
+        let two = rel.bigfloat_from_f64(2.0);
+        let c = &c;
+        let two = &two;
+        let expected = expr!(two * c, &mut rel.ctx);
+
+        let places = matching_decimal_places(&interval, &expected);
+        assert!(places >= 290, "spacetime interval only matched {places} decimal places");
+    }
 }
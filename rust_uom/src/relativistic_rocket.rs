@@ -0,0 +1,62 @@
+// Relativistic rocket equations: the trajectory of an object under constant *proper*
+// acceleration (the acceleration felt onboard), as opposed to `relativistic_acceleration`
+// which only gives the resulting velocity. Use `STANDARD_GRAVITY` as a comfortable,
+// sustainable proper acceleration to answer questions like "how long to cross the
+// galaxy at 1g?"
+
+use crate::tools::RelativityContext;
+use uom::si::acceleration::meter_per_second_squared;
+use uom::si::f64::{Acceleration, Length, Time, Velocity};
+use uom::si::length::meter;
+use uom::si::time::second;
+use uom::si::velocity::meter_per_second;
+
+/// Distance traveled under constant proper acceleration `a` over elapsed coordinate
+/// time `t`: `d = (c^2/a)*(sqrt(1 + (a*t/c)^2) - 1)`
+pub fn distance_from_coordinate_time(context: RelativityContext, a: Acceleration, t: Time) -> Length {
+    let a_val = a.get::<meter_per_second_squared>();
+    let t_val = t.get::<second>();
+    let a_t_over_c = a_val * t_val / context.c;
+
+    Length::new::<meter>(context.c_squared / a_val * ((1_f64 + a_t_over_c.powi(2)).sqrt() - 1_f64))
+}
+
+/// Elapsed proper (ship) time under constant proper acceleration `a` over elapsed
+/// coordinate time `t`: `tau = (c/a)*asinh(a*t/c)`
+pub fn proper_time_from_coordinate_time(context: RelativityContext, a: Acceleration, t: Time) -> Time {
+    let a_val = a.get::<meter_per_second_squared>();
+    let t_val = t.get::<second>();
+    let a_t_over_c = a_val * t_val / context.c;
+
+    Time::new::<second>(context.c / a_val * a_t_over_c.asinh())
+}
+
+/// Elapsed coordinate time under constant proper acceleration `a` over elapsed proper
+/// (ship) time `tau`: `t = (c/a)*sinh(a*tau/c)`
+pub fn coordinate_time_from_proper_time(context: RelativityContext, a: Acceleration, tau: Time) -> Time {
+    let a_val = a.get::<meter_per_second_squared>();
+    let tau_val = tau.get::<second>();
+    let a_tau_over_c = a_val * tau_val / context.c;
+
+    Time::new::<second>(context.c / a_val * a_tau_over_c.sinh())
+}
+
+/// Distance traveled under constant proper acceleration `a` over elapsed proper (ship)
+/// time `tau`: `d = (c^2/a)*(cosh(a*tau/c) - 1)`
+pub fn distance_from_proper_time(context: RelativityContext, a: Acceleration, tau: Time) -> Length {
+    let a_val = a.get::<meter_per_second_squared>();
+    let tau_val = tau.get::<second>();
+    let a_tau_over_c = a_val * tau_val / context.c;
+
+    Length::new::<meter>(context.c_squared / a_val * (a_tau_over_c.cosh() - 1_f64))
+}
+
+/// Velocity reached under constant proper acceleration `a` over elapsed proper (ship)
+/// time `tau`: `beta = tanh(a*tau/c)`
+pub fn velocity_from_proper_time(context: RelativityContext, a: Acceleration, tau: Time) -> Velocity {
+    let a_val = a.get::<meter_per_second_squared>();
+    let tau_val = tau.get::<second>();
+    let a_tau_over_c = a_val * tau_val / context.c;
+
+    Velocity::new::<meter_per_second>(context.c * a_tau_over_c.tanh())
+}
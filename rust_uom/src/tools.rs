@@ -12,6 +12,7 @@
 // Rapidity = wrapped f64 representing the rapidity of an object, which allows easier calculations than velocity
 
 use std::ops::Add;
+use std::time::Duration;
 use uom::si::acceleration::meter_per_second_squared;
 use uom::si::energy::joule;
 use uom::si::f64::{Acceleration, Energy, Length, Mass, Time, Velocity};
@@ -23,6 +24,34 @@ pub const C_MPS: f64 = 299_792_458.0;
 pub const C_SQUARED: f64 = C_MPS * C_MPS;
 pub const STANDARD_GRAVITY: f64 = 9.80665;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Configurable speed of light for the relativity calculations, so the whole suite can
+/// be run against an arbitrary `c` (e.g. a "slow light" world where relativistic effects
+/// are visible at human speeds) instead of always assuming the real 299,792,458 m/s
+pub struct RelativityContext {
+    pub c: f64,
+    /// c squared, cached since almost every relativistic formula needs it
+    pub c_squared: f64,
+}
+
+impl RelativityContext {
+    /// Context using the real (SI) speed of light
+    pub fn si() -> Self {
+        Self::with_c(C_MPS)
+    }
+
+    /// Context using an arbitrary speed of light
+    pub fn with_c(c: f64) -> Self {
+        RelativityContext { c, c_squared: c * c }
+    }
+}
+
+impl Default for RelativityContext {
+    fn default() -> Self {
+        Self::si()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 /// Lorentz factor, 1 or greater, calculated from 1 / sqrt(1 - v^2/c^2)
 pub struct LorentzFactor {
@@ -46,16 +75,21 @@ impl LorentzFactor {
         }
     }
 
-    /// Create a new `LorentzFactor` from a rapidity
+    /// Create a new `LorentzFactor` from a rapidity, via `cosh` directly rather than
+    /// through `FractionOfC` and `1/sqrt(1-beta^2)`, so ultra-relativistic rapidities
+    /// don't lose precision to catastrophic cancellation
     pub fn from_rapidity(r: Rapidity) -> Self {
-        Self::from_fraction_of_c(FractionOfC::from_rapidity(r))
+        LorentzFactor {
+            value: r.get().cosh(),
+        }
     }
 
     /// Create a new `LorentzFactor` from a velocity
-    pub fn from_velocity(v: Velocity) -> Self {
+    pub fn from_velocity(context: RelativityContext, v: Velocity) -> Self {
         LorentzFactor {
             value: 1_f64
-                / (1_f64 - (validate_velocity(v).get::<meter_per_second>() / C_MPS).powi(2)).sqrt(),
+                / (1_f64 - (validate_velocity(context, v).get::<meter_per_second>() / context.c).powi(2))
+                    .sqrt(),
         }
     }
 
@@ -75,9 +109,45 @@ impl LorentzFactor {
     }
 
     /// Calculate the relativistic energy from a rest mass
-    pub fn relativistic_energy(self, rest_mass: Mass) -> Energy {
-        Energy::new::<joule>(rest_mass.get::<kilogram>() * C_SQUARED * self.value)
+    pub fn relativistic_energy(self, context: RelativityContext, rest_mass: Mass) -> Energy {
+        Energy::new::<joule>(rest_mass.get::<kilogram>() * context.c_squared * self.value)
+    }
+
+    /// Dilate a proper-time `Duration` into the corresponding coordinate-time
+    /// `Duration`. Splits whole seconds and nanoseconds apart rather than
+    /// round-tripping through a single lossy f64 seconds value, and saturates to
+    /// `Duration::MAX` if the dilated result would overflow
+    pub fn dilate_duration(self, proper: Duration) -> Duration {
+        scale_duration(proper, self.value)
+    }
+
+    /// Inverse of `dilate_duration`: contract a coordinate-time `Duration` back down
+    /// to the corresponding proper-time `Duration`
+    pub fn contract_duration(self, dilated: Duration) -> Duration {
+        scale_duration(dilated, 1_f64 / self.value)
+    }
+}
+
+/// Scale a `Duration` by a positive floating-point factor, splitting whole seconds and
+/// nanoseconds apart so sub-second precision survives rather than round-tripping
+/// through a single lossy f64 seconds value. Saturates to `Duration::MAX` on overflow
+fn scale_duration(d: Duration, factor: f64) -> Duration {
+    let scaled_secs = d.as_secs() as f64 * factor;
+    let secs = scaled_secs.floor();
+    // the scaled whole seconds may have gained a fractional part; carry it into `nanos`
+    let nanos = (scaled_secs - secs) * 1_000_000_000_f64 + f64::from(d.subsec_nanos()) * factor;
+
+    // `nanos` may now be worth more than a second; fold its whole-second part back
+    // into `secs` before splitting the remainder back out
+    let extra_secs = (nanos / 1_000_000_000_f64).floor();
+    let secs_total = secs + extra_secs;
+    let nanos_remaining = (nanos - extra_secs * 1_000_000_000_f64).round() as u32;
+
+    if !(0.0..=(u64::MAX as f64)).contains(&secs_total) {
+        return Duration::MAX;
     }
+
+    Duration::new(secs_total as u64, nanos_remaining.min(999_999_999))
 }
 
 impl std::fmt::Display for LorentzFactor {
@@ -104,8 +174,8 @@ impl FractionOfC {
     }
 
     /// Helper to turn a fraction of C into a velocity
-    pub fn get_velocity(fraction: f64) -> Velocity {
-        Self::new(fraction, true).as_velocity()
+    pub fn get_velocity(context: RelativityContext, fraction: f64) -> Velocity {
+        Self::new(fraction, true).as_velocity(context)
     }
 
     /// Get the value of the fraction
@@ -114,19 +184,19 @@ impl FractionOfC {
     }
 
     /// Convert the fraction to a velocity
-    pub fn as_velocity(self) -> Velocity {
-        Velocity::new::<meter_per_second>(self.value * C_MPS)
+    pub fn as_velocity(self, context: RelativityContext) -> Velocity {
+        Velocity::new::<meter_per_second>(self.value * context.c)
     }
 
     /// Create a new `FractionOfC` from a velocity, optionally checking that the velocity is less than the speed of light
-    pub fn from_velocity(v: Velocity, check_c: bool) -> Self {
+    pub fn from_velocity(context: RelativityContext, v: Velocity, check_c: bool) -> Self {
         if check_c {
             FractionOfC {
-                value: validate_velocity(v).get::<meter_per_second>() / C_MPS,
+                value: validate_velocity(context, v).get::<meter_per_second>() / context.c,
             }
         } else {
             FractionOfC {
-                value: v.get::<meter_per_second>() / C_MPS,
+                value: v.get::<meter_per_second>() / context.c,
             }
         }
     }
@@ -164,21 +234,21 @@ impl Rapidity {
     }
 
     /// Convert the rapidity to a velocity
-    pub fn to_velocity(self) -> Velocity {
-        Velocity::new::<meter_per_second>(C_MPS * self.value.tanh())
+    pub fn to_velocity(self, context: RelativityContext) -> Velocity {
+        Velocity::new::<meter_per_second>(context.c * self.value.tanh())
     }
 
     /// Create a new `Rapidity` from a velocity
-    pub fn from_velocity(v: Velocity) -> Self {
+    pub fn from_velocity(context: RelativityContext, v: Velocity) -> Self {
         Rapidity {
-            value: (validate_velocity(v).get::<meter_per_second>() / C_MPS).atanh(),
+            value: (validate_velocity(context, v).get::<meter_per_second>() / context.c).atanh(),
         }
     }
 
     /// Create a new `Rapidity` from an acceleration and a time
-    pub fn from_acc_and_time(acc: Acceleration, time: Time) -> Self {
+    pub fn from_acc_and_time(context: RelativityContext, acc: Acceleration, time: Time) -> Self {
         Rapidity {
-            value: acc.get::<meter_per_second_squared>() * time.get::<second>() / C_MPS,
+            value: acc.get::<meter_per_second_squared>() * time.get::<second>() / context.c,
         }
     }
 
@@ -211,9 +281,9 @@ impl std::fmt::Display for Rapidity {
 // =================================================================================================
 
 #[inline]
-/// Check the velocity is less than the speed of light
-pub fn validate_velocity_result(v: Velocity) -> anyhow::Result<Velocity> {
-    if v.get::<meter_per_second>().abs() < C_MPS {
+/// Check the velocity is less than the configured speed of light
+pub fn validate_velocity_result(context: RelativityContext, v: Velocity) -> anyhow::Result<Velocity> {
+    if v.get::<meter_per_second>().abs() < context.c {
         Ok(v)
     } else {
         Err(anyhow::anyhow!(
@@ -223,9 +293,9 @@ pub fn validate_velocity_result(v: Velocity) -> anyhow::Result<Velocity> {
 }
 
 #[inline]
-/// Check the velocity is less than the speed of light, panicking if it is not
-pub fn validate_velocity(v: Velocity) -> Velocity {
-    validate_velocity_result(v).unwrap()
+/// Check the velocity is less than the configured speed of light, panicking if it is not
+pub fn validate_velocity(context: RelativityContext, v: Velocity) -> Velocity {
+    validate_velocity_result(context, v).unwrap()
 }
 
 /// Naive velocity calculation from acceleration and time
@@ -234,60 +304,284 @@ pub fn non_relativistic_acceleration(acc: Acceleration, time: Time) -> Velocity
 }
 
 /// Calculate the relativistic velocity due to constant acceleration, from an acceleration and time
-pub fn relativistic_acceleration(acc: Acceleration, time: Time) -> Velocity {
+pub fn relativistic_acceleration(context: RelativityContext, acc: Acceleration, time: Time) -> Velocity {
     Velocity::new::<meter_per_second>(
-        C_MPS * ((acc.get::<meter_per_second_squared>() * time.get::<second>()) / C_MPS).tanh(),
+        context.c
+            * ((acc.get::<meter_per_second_squared>() * time.get::<second>()) / context.c).tanh(),
     )
 }
 
 /// Calculate the relativistic velocity due to constant acceleration, as fraction of c
-pub fn relativistic_acceleration_as_fraction(acc: Acceleration, time: Time) -> FractionOfC {
-    let rapidity = Rapidity::from_acc_and_time(acc, time);
+pub fn relativistic_acceleration_as_fraction(
+    context: RelativityContext,
+    acc: Acceleration,
+    time: Time,
+) -> FractionOfC {
+    let rapidity = Rapidity::from_acc_and_time(context, acc, time);
     FractionOfC::from_rapidity(rapidity)
 }
 
 /// Calculate the relativistic velocity due to constant acceleration, from an initial velocity, acceleration, and time
 pub fn relativistic_acceleration_add(
+    context: RelativityContext,
     initial_vel: Velocity,
     acc: Acceleration,
     time: Time,
 ) -> Velocity {
     // Calculate the rapidity corresponding to the initial velocity
-    let initial_rapidity = Rapidity::from_velocity(initial_vel);
+    let initial_rapidity = Rapidity::from_velocity(context, initial_vel);
 
     // Calculate the rapidity gained due to constant acceleration
-    let acceleration_rapidity = Rapidity::from_acc_and_time(acc, time);
+    let acceleration_rapidity = Rapidity::from_acc_and_time(context, acc, time);
 
     // Add the two rapidities together to get the total rapidity
     let total_rapidity = initial_rapidity + acceleration_rapidity;
 
-    total_rapidity.to_velocity()
+    total_rapidity.to_velocity(context)
 }
 
 /// Add two velocities together using rapidity
-pub fn add_velocities_using_rapidity(v1: Velocity, v2: Velocity) -> Velocity {
-    let rapidity1 = Rapidity::from_velocity(v1);
-    let rapidity2 = Rapidity::from_velocity(v2);
+pub fn add_velocities_using_rapidity(context: RelativityContext, v1: Velocity, v2: Velocity) -> Velocity {
+    let rapidity1 = Rapidity::from_velocity(context, v1);
+    let rapidity2 = Rapidity::from_velocity(context, v2);
     let total_rapidity = rapidity1 + rapidity2;
 
-    total_rapidity.to_velocity()
+    total_rapidity.to_velocity(context)
 }
 
 /// Add two velocities together using fractions of the speed of light
-pub fn add_velocities2(v1: Velocity, v2: Velocity) -> Velocity {
-    let fraction1 = FractionOfC::from_velocity(v1, true);
-    let fraction2 = FractionOfC::from_velocity(v2, true);
+pub fn add_velocities2(context: RelativityContext, v1: Velocity, v2: Velocity) -> Velocity {
+    let fraction1 = FractionOfC::from_velocity(context, v1, true);
+    let fraction2 = FractionOfC::from_velocity(context, v2, true);
 
     Velocity::new::<meter_per_second>(
-        C_MPS * (fraction1.get() + fraction2.get()) / (1_f64 + (fraction1.get() * fraction2.get())),
+        context.c * (fraction1.get() + fraction2.get())
+            / (1_f64 + (fraction1.get() * fraction2.get())),
     )
 }
 
 /// Add two velocities together using the relativistic velocity addition formula
-pub fn add_velocities3(v1: Velocity, v2: Velocity) -> Velocity {
-    let u = validate_velocity(v1).get::<meter_per_second>();
-    let v = validate_velocity(v2).get::<meter_per_second>();
+pub fn add_velocities3(context: RelativityContext, v1: Velocity, v2: Velocity) -> Velocity {
+    let u = validate_velocity(context, v1).get::<meter_per_second>();
+    let v = validate_velocity(context, v2).get::<meter_per_second>();
 
-    let resulting_velocity = (u + v) / (1_f64 + (u * v / C_SQUARED));
+    let resulting_velocity = (u + v) / (1_f64 + (u * v / context.c_squared));
     Velocity::new::<meter_per_second>(resulting_velocity)
 }
+
+// =================================================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A 3D velocity vector, for the general (non-collinear) relativistic velocity
+/// addition in `boost_velocity`. `add_velocities2`/`add_velocities3` only handle the
+/// collinear case
+pub struct Velocity3 {
+    pub x: Velocity,
+    pub y: Velocity,
+    pub z: Velocity,
+}
+
+impl Velocity3 {
+    pub fn new(x: Velocity, y: Velocity, z: Velocity) -> Self {
+        Velocity3 { x, y, z }
+    }
+
+    /// Magnitude (speed) of this velocity vector
+    pub fn magnitude(self) -> Velocity {
+        Velocity::new::<meter_per_second>(self.dot(self).sqrt())
+    }
+
+    /// Dot product of two velocity vectors, in (m/s)^2
+    fn dot(self, other: Self) -> f64 {
+        self.x.get::<meter_per_second>() * other.x.get::<meter_per_second>()
+            + self.y.get::<meter_per_second>() * other.y.get::<meter_per_second>()
+            + self.z.get::<meter_per_second>() * other.z.get::<meter_per_second>()
+    }
+
+    /// Magnitude of the cross product of two velocity vectors, in (m/s)^2
+    fn cross_magnitude(self, other: Self) -> f64 {
+        let ux = self.x.get::<meter_per_second>();
+        let uy = self.y.get::<meter_per_second>();
+        let uz = self.z.get::<meter_per_second>();
+        let vx = other.x.get::<meter_per_second>();
+        let vy = other.y.get::<meter_per_second>();
+        let vz = other.z.get::<meter_per_second>();
+
+        let cx = uy * vz - uz * vy;
+        let cy = uz * vx - ux * vz;
+        let cz = ux * vy - uy * vx;
+
+        (cx * cx + cy * cy + cz * cz).sqrt()
+    }
+
+    /// Lorentz factor of this velocity vector
+    fn lorentz_factor(self, context: RelativityContext) -> f64 {
+        1_f64 / (1_f64 - self.dot(self) / context.c_squared).sqrt()
+    }
+}
+
+/// Compose a frame velocity `u` and an object velocity `v` (as measured in the frame
+/// moving at `u`) using the general, non-collinear relativistic velocity addition
+/// formula. Reduces to `add_velocities2`/`add_velocities3` when `u` and `v` are
+/// parallel, and always returns a speed less than c
+pub fn boost_velocity(context: RelativityContext, frame_u: Velocity3, object_v: Velocity3) -> Velocity3 {
+    let ux = frame_u.x.get::<meter_per_second>();
+    let uy = frame_u.y.get::<meter_per_second>();
+    let uz = frame_u.z.get::<meter_per_second>();
+    let vx = object_v.x.get::<meter_per_second>();
+    let vy = object_v.y.get::<meter_per_second>();
+    let vz = object_v.z.get::<meter_per_second>();
+
+    let u_dot_v = frame_u.dot(object_v);
+    let gamma_u = frame_u.lorentz_factor(context);
+    let scale = 1_f64 / (1_f64 + u_dot_v / context.c_squared);
+    let coefficient = (gamma_u / (gamma_u + 1_f64)) * (u_dot_v / context.c_squared);
+
+    let sx = scale * (ux + vx / gamma_u + coefficient * ux);
+    let sy = scale * (uy + vy / gamma_u + coefficient * uy);
+    let sz = scale * (uz + vz / gamma_u + coefficient * uz);
+
+    Velocity3::new(
+        Velocity::new::<meter_per_second>(sx),
+        Velocity::new::<meter_per_second>(sy),
+        Velocity::new::<meter_per_second>(sz),
+    )
+}
+
+/// Wigner rotation angle (radians) between boosting by `u` then `v` versus `v` then
+/// `u` — non-collinear velocity addition is non-commutative. Zero when `u` and `v` are
+/// parallel, matching the scalar addition formulas
+pub fn wigner_rotation_angle(context: RelativityContext, u: Velocity3, v: Velocity3) -> f64 {
+    let gamma_u = u.lorentz_factor(context);
+    let gamma_v = v.lorentz_factor(context);
+    let u_dot_v = u.dot(v);
+    let cross_magnitude = u.cross_magnitude(v);
+
+    let numerator = (cross_magnitude / context.c_squared) * (gamma_u + gamma_v + gamma_u * gamma_v + 1_f64);
+    let denominator =
+        (gamma_u + 1_f64) * (gamma_v + 1_f64) + gamma_u * gamma_v * (u_dot_v / context.c_squared);
+
+    2_f64 * (numerator / denominator).atan()
+}
+
+// =================================================================================================
+// High-precision backend: these types use f64, so are fast but lack accuracy for
+// velocities extremely close to c, where `1 - v^2/c^2` loses nearly all significant
+// bits in a double. `LorentzFactorHP`/`FractionOfCHP`/`RapidityHP` are exact
+// `BigRational`-backed counterparts living in `tools_rational`; these are thin aliases
+// plus conversions so callers can drop down to full precision only where it matters
+
+/// High-precision counterpart of `LorentzFactor`, backed by an exact `BigRational`
+pub type LorentzFactorHP = crate::tools_rational::LorentzFactor;
+/// High-precision counterpart of `FractionOfC`, backed by an exact `BigRational`
+pub type FractionOfCHP = crate::tools_rational::FractionOfC;
+/// High-precision counterpart of `Rapidity`, backed by an exact `BigRational`
+pub type RapidityHP = crate::tools_rational::Rapidity;
+
+/// Convert a high-precision Lorentz factor down to the fast, f64-backed `LorentzFactor`
+pub fn lorentz_factor_from_hp(hp: &LorentzFactorHP) -> LorentzFactor {
+    LorentzFactor::new(hp.get_f64())
+}
+
+/// Convert a fast, f64-backed `LorentzFactor` up to a high-precision `LorentzFactorHP`
+pub fn lorentz_factor_to_hp(lf: LorentzFactor) -> LorentzFactorHP {
+    LorentzFactorHP::new(crate::tools_rational::bigrational_from_f64(lf.get()))
+}
+
+/// Convert a high-precision fraction of c down to the fast, f64-backed `FractionOfC`
+pub fn fraction_of_c_from_hp(hp: &FractionOfCHP) -> FractionOfC {
+    FractionOfC::new(hp.get_f64(), false)
+}
+
+/// Convert a fast, f64-backed `FractionOfC` up to a high-precision `FractionOfCHP`
+pub fn fraction_of_c_to_hp(f: FractionOfC) -> FractionOfCHP {
+    FractionOfCHP::new(crate::tools_rational::bigrational_from_f64(f.get()), false)
+}
+
+/// Convert a high-precision rapidity down to the fast, f64-backed `Rapidity`
+pub fn rapidity_from_hp(hp: &RapidityHP) -> Rapidity {
+    Rapidity::new(hp.get_f64())
+}
+
+/// Convert a fast, f64-backed `Rapidity` up to a high-precision `RapidityHP`
+pub fn rapidity_to_hp(r: Rapidity) -> RapidityHP {
+    RapidityHP::new(crate::tools_rational::bigrational_from_f64(r.get()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn velocity3_along_x(context: RelativityContext, fraction_of_c: f64) -> Velocity3 {
+        Velocity3::new(
+            Velocity::new::<meter_per_second>(context.c * fraction_of_c),
+            Velocity::new::<meter_per_second>(0.0),
+            Velocity::new::<meter_per_second>(0.0),
+        )
+    }
+
+    #[test]
+    fn test_boost_velocity_reduces_to_scalar_when_collinear() {
+        let context = RelativityContext::si();
+        let u = velocity3_along_x(context, 0.6);
+        let v = velocity3_along_x(context, 0.5);
+
+        let boosted = boost_velocity(context, u, v);
+        let scalar = add_velocities3(context, u.x, v.x);
+
+        assert!((boosted.x.get::<meter_per_second>() - scalar.get::<meter_per_second>()).abs() < 1e-6);
+        assert!(boosted.y.get::<meter_per_second>().abs() < 1e-9);
+        assert!(boosted.z.get::<meter_per_second>().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_boost_velocity_stays_below_c() {
+        let context = RelativityContext::si();
+        let u = Velocity3::new(
+            Velocity::new::<meter_per_second>(context.c * 0.9),
+            Velocity::new::<meter_per_second>(context.c * 0.2),
+            Velocity::new::<meter_per_second>(0.0),
+        );
+        let v = Velocity3::new(
+            Velocity::new::<meter_per_second>(context.c * 0.1),
+            Velocity::new::<meter_per_second>(0.0),
+            Velocity::new::<meter_per_second>(context.c * 0.8),
+        );
+
+        let boosted = boost_velocity(context, u, v);
+
+        assert!(boosted.magnitude().get::<meter_per_second>() < context.c);
+    }
+
+    #[test]
+    fn test_wigner_rotation_angle_zero_when_collinear() {
+        let context = RelativityContext::si();
+        let u = velocity3_along_x(context, 0.6);
+        let v = velocity3_along_x(context, 0.3);
+
+        let angle = wigner_rotation_angle(context, u, v);
+
+        assert!(angle.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_wigner_rotation_angle_perpendicular_boosts() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let context = RelativityContext::si();
+        let u = velocity3_along_x(context, 0.5);
+        let v = Velocity3::new(
+            Velocity::new::<meter_per_second>(0.0),
+            Velocity::new::<meter_per_second>(context.c * 0.5),
+            Velocity::new::<meter_per_second>(0.0),
+        );
+
+        let angle = wigner_rotation_angle(context, u, v);
+
+        // tan(angle/2) = |beta_u x beta_v| * (gamma_u+1)(gamma_v+1) / [(gamma_u+1)(gamma_v+1) +
+        // gamma_u*gamma_v*(beta_u . beta_v)], which for perpendicular betas of equal magnitude
+        // 0.5 reduces to tan(angle/2) = 0.25, i.e. angle = 2*atan(0.25)
+        assert!((angle - 2.0 * 0.25_f64.atan()).abs() < 1e-9);
+        assert!(angle > 0.0 && angle < FRAC_PI_2);
+    }
+}
@@ -16,6 +16,7 @@ use uom::num::BigInt;
 use uom::si::acceleration::meter_per_second_squared;
 use uom::si::bigrational::{Acceleration, Energy, Length, Mass, Time, Velocity};
 use uom::si::energy::joule;
+use uom::si::length::meter;
 use uom::si::mass::kilogram;
 use uom::si::time::second;
 use uom::si::velocity::meter_per_second;
@@ -25,9 +26,10 @@ const C_INT: u64 = 299_792_458;
 const STANDARD_GRAVITY_NOM: u64 = 980_665; // 980,665 / 100000
 const STANDARD_GRAVITY_DENOM: u64 = 100_000;
 
-// lazy statics for the BigRational speed of light, c^2, and standard gravity
+// lazy statics for the BigRational speed of light and standard gravity. `c` itself now
+// lives on `BigRationalConfig` (see `BigRationalConfig::si`/`natural`/`with_c`) so it
+// can be overridden per context; this remains as the canonical SI value it defaults to
 pub static C_BR: Lazy<BigRational> = Lazy::new(|| bigrational_from_int(C_INT));
-pub static C_SQUARED_BR: Lazy<BigRational> = Lazy::new(|| &*C_BR * &*C_BR);
 pub static STANDARD_GRAVITY_BR: Lazy<BigRational> =
     Lazy::new(|| bigrational_from_ratio(STANDARD_GRAVITY_NOM, STANDARD_GRAVITY_DENOM));
 
@@ -60,15 +62,19 @@ impl LorentzFactor {
         }
     }
 
-    /// Create a new `LorentzFactor` from a rapidity
+    /// Create a new `LorentzFactor` from a rapidity, via `cosh` directly rather than
+    /// through `FractionOfC` and `1/sqrt(1-beta^2)`, so this stays numerically stable
+    /// (and avoids an extra `sqrt` Newton iteration) right up to c
     pub fn from_rapidity(config: &BigRationalConfig, r: &Rapidity) -> Self {
-        Self::from_fraction_of_c(config, &FractionOfC::from_rapidity(config, r))
+        LorentzFactor {
+            value: config.cosh(r.get()),
+        }
     }
 
     /// Create a new `LorentzFactor` from a velocity
     pub fn from_velocity(config: &BigRationalConfig, v: &Velocity) -> Self {
-        let vel: BigRational = validate_velocity(v).get::<meter_per_second>();
-        let vel_over_c = vel / &*C_BR;
+        let vel: BigRational = validate_velocity(config, v).get::<meter_per_second>();
+        let vel_over_c = vel / &config.c;
         let one_minus = BigRational::one() - vel_over_c.pow(2);
         LorentzFactor {
             value: BigRational::one() / config.sqrt(&one_minus),
@@ -91,8 +97,8 @@ impl LorentzFactor {
     }
 
     /// Calculate the relativistic energy from a rest mass
-    pub fn relativistic_energy(&self, rest_mass: &Mass) -> Energy {
-        Energy::new::<joule>(rest_mass.get::<kilogram>() * &*C_SQUARED_BR * &self.value)
+    pub fn relativistic_energy(&self, config: &BigRationalConfig, rest_mass: &Mass) -> Energy {
+        Energy::new::<joule>(rest_mass.get::<kilogram>() * &config.c_squared * &self.value)
     }
 }
 
@@ -120,13 +126,13 @@ impl FractionOfC {
     }
 
     /// Helper to turn a fraction of C f64 into a velocity
-    pub fn get_velocity_f64(fraction: f64) -> Velocity {
-        Self::new(bigrational_from_f64(fraction), true).as_velocity()
+    pub fn get_velocity_f64(config: &BigRationalConfig, fraction: f64) -> Velocity {
+        Self::new(bigrational_from_f64(fraction), true).as_velocity(config)
     }
 
     /// Helper to turn a fraction of C into a velocity
-    pub fn get_velocity(fraction: BigRational) -> Velocity {
-        Self::new(fraction, true).as_velocity()
+    pub fn get_velocity(config: &BigRationalConfig, fraction: BigRational) -> Velocity {
+        Self::new(fraction, true).as_velocity(config)
     }
 
     /// Get the value of the fraction
@@ -140,19 +146,19 @@ impl FractionOfC {
     }
 
     /// Convert the fraction to a velocity
-    pub fn as_velocity(&self) -> Velocity {
-        Velocity::new::<meter_per_second>(&self.value * &*C_BR)
+    pub fn as_velocity(&self, config: &BigRationalConfig) -> Velocity {
+        Velocity::new::<meter_per_second>(&self.value * &config.c)
     }
 
     /// Create a new `FractionOfC` from a velocity, optionally checking that the velocity is less than the speed of light
-    pub fn from_velocity(v: &Velocity, check_c: bool) -> Self {
+    pub fn from_velocity(config: &BigRationalConfig, v: &Velocity, check_c: bool) -> Self {
         if check_c {
             FractionOfC {
-                value: validate_velocity(v).get::<meter_per_second>() / &*C_BR,
+                value: validate_velocity(config, v).get::<meter_per_second>() / &config.c,
             }
         } else {
             FractionOfC {
-                value: v.get::<meter_per_second>() / &*C_BR,
+                value: v.get::<meter_per_second>() / &config.c,
             }
         }
     }
@@ -196,20 +202,20 @@ impl Rapidity {
 
     /// Convert the rapidity to a velocity
     pub fn to_velocity(&self, config: &BigRationalConfig) -> Velocity {
-        Velocity::new::<meter_per_second>(&*C_BR * config.tanh(&self.value))
+        Velocity::new::<meter_per_second>(&config.c * config.tanh(&self.value))
     }
 
     /// Create a new `Rapidity` from a velocity
     pub fn from_velocity(config: &BigRationalConfig, v: &Velocity) -> Self {
         Rapidity {
-            value: config.atanh(&(validate_velocity(v).get::<meter_per_second>() / &*C_BR)),
+            value: config.atanh(&(validate_velocity(config, v).get::<meter_per_second>() / &config.c)),
         }
     }
 
     /// Create a new `Rapidity` from an acceleration and a time
-    pub fn from_acc_and_time(acc: &Acceleration, time: &Time) -> Self {
+    pub fn from_acc_and_time(config: &BigRationalConfig, acc: &Acceleration, time: &Time) -> Self {
         Rapidity {
-            value: acc.get::<meter_per_second_squared>() * time.get::<second>() / &*C_BR,
+            value: acc.get::<meter_per_second_squared>() * time.get::<second>() / &config.c,
         }
     }
 
@@ -242,9 +248,9 @@ impl std::fmt::Display for Rapidity {
 // =================================================================================================
 
 #[inline]
-/// Check the velocity is less than the speed of light
-pub fn validate_velocity_result(v: &Velocity) -> anyhow::Result<&Velocity> {
-    if v.get::<meter_per_second>().abs() < *C_BR {
+/// Check the velocity is less than the configured speed of light
+pub fn validate_velocity_result(config: &BigRationalConfig, v: &Velocity) -> anyhow::Result<&Velocity> {
+    if v.get::<meter_per_second>().abs() < config.c {
         Ok(v)
     } else {
         Err(anyhow::anyhow!(
@@ -254,9 +260,9 @@ pub fn validate_velocity_result(v: &Velocity) -> anyhow::Result<&Velocity> {
 }
 
 #[inline]
-/// Check the velocity is less than the speed of light, panicking if it is not
-pub fn validate_velocity(v: &Velocity) -> &Velocity {
-    validate_velocity_result(v).unwrap()
+/// Check the velocity is less than the configured speed of light, panicking if it is not
+pub fn validate_velocity<'a>(config: &BigRationalConfig, v: &'a Velocity) -> &'a Velocity {
+    validate_velocity_result(config, v).unwrap()
 }
 
 /// Naive velocity calculation from acceleration and time
@@ -270,8 +276,8 @@ pub fn relativistic_acceleration(
     acc: &Acceleration,
     time: &Time,
 ) -> Velocity {
-    let a = (acc.get::<meter_per_second_squared>() * time.get::<second>()) / &*C_BR;
-    Velocity::new::<meter_per_second>(&*C_BR * config.tanh(&a))
+    let a = (acc.get::<meter_per_second_squared>() * time.get::<second>()) / &config.c;
+    Velocity::new::<meter_per_second>(&config.c * config.tanh(&a))
 }
 
 /// Calculate the relativistic velocity due to constant acceleration, as fraction of c
@@ -280,7 +286,7 @@ pub fn relativistic_acceleration_as_fraction(
     acc: &Acceleration,
     time: &Time,
 ) -> FractionOfC {
-    let rapidity = Rapidity::from_acc_and_time(acc, time);
+    let rapidity = Rapidity::from_acc_and_time(config, acc, time);
     FractionOfC::from_rapidity(config, &rapidity)
 }
 
@@ -295,7 +301,7 @@ pub fn relativistic_acceleration_add(
     let initial_rapidity = Rapidity::from_velocity(config, initial_vel);
 
     // Calculate the rapidity gained due to constant acceleration
-    let acceleration_rapidity = Rapidity::from_acc_and_time(acc, time);
+    let acceleration_rapidity = Rapidity::from_acc_and_time(config, acc, time);
 
     // Add the two rapidities together to get the total rapidity
     let total_rapidity = initial_rapidity + acceleration_rapidity;
@@ -303,13 +309,47 @@ pub fn relativistic_acceleration_add(
     total_rapidity.to_velocity(config)
 }
 
+/// Distance traveled under constant proper acceleration `acc` over proper (onboard)
+/// time `time`: `d = (c^2/a) * (cosh(a*tau/c) - 1)`. Completes the "relativistic rocket"
+/// kinematics alongside `relativistic_acceleration`/`Rapidity::from_acc_and_time`,
+/// which only give the final velocity, not the distance traveled to reach it
+pub fn distance_under_acceleration(
+    config: &BigRationalConfig,
+    acc: &Acceleration,
+    time: &Time,
+) -> Length {
+    let a = acc.get::<meter_per_second_squared>();
+    let t = time.get::<second>();
+    let arg = &a * &t / &config.c;
+    let cosh_arg = config.cosh(&arg);
+
+    let distance = (&config.c_squared / &a) * (cosh_arg - BigRational::one());
+    Length::new::<meter>(config.rationalize(&distance))
+}
+
+/// Elapsed proper (onboard ship) time for coordinate time `coordinate_time` under
+/// constant proper acceleration `acc`: `tau = (c/a) * asinh(a*t/c)`, where
+/// `asinh(x) = ln(x + sqrt(x^2 + 1))`
+pub fn proper_time_under_acceleration(
+    config: &BigRationalConfig,
+    acc: &Acceleration,
+    coordinate_time: &Time,
+) -> Time {
+    let a = acc.get::<meter_per_second_squared>();
+    let t = coordinate_time.get::<second>();
+    let arg = &a * &t / &config.c;
+    let asinh_arg = config.ln(&(&arg + config.sqrt(&(&arg * &arg + BigRational::one()))));
+
+    let tau = (&config.c / &a) * asinh_arg;
+    Time::new::<second>(config.rationalize(&tau))
+}
+
 /// Add two velocities together using rapidity
 pub fn add_velocities_using_rapidity(
     config: &BigRationalConfig,
     v1: &Velocity,
     v2: &Velocity,
 ) -> Velocity {
-    // *** VERY SLOW, rationals blow up in size ***
     let rapidity1 = Rapidity::from_velocity(config, v1);
     let rapidity2 = Rapidity::from_velocity(config, v2);
     let total_rapidity = rapidity1 + rapidity2;
@@ -318,24 +358,23 @@ pub fn add_velocities_using_rapidity(
 }
 
 /// Add two velocities together using fractions of the speed of light
-pub fn add_velocities2(v1: &Velocity, v2: &Velocity) -> Velocity {
-    let fraction1 = FractionOfC::from_velocity(v1, true);
-    let fraction2 = FractionOfC::from_velocity(v2, true);
+pub fn add_velocities2(config: &BigRationalConfig, v1: &Velocity, v2: &Velocity) -> Velocity {
+    let fraction1 = FractionOfC::from_velocity(config, v1, true);
+    let fraction2 = FractionOfC::from_velocity(config, v2, true);
 
-    Velocity::new::<meter_per_second>(
-        &*C_BR * (fraction1.get() + fraction2.get())
-            / (BigRational::one() + (fraction1.get() * fraction2.get())),
-    )
+    let result = &config.c * (fraction1.get() + fraction2.get())
+        / (BigRational::one() + (fraction1.get() * fraction2.get()));
+    Velocity::new::<meter_per_second>(config.rationalize(&result))
 }
 
 /// Add two velocities together using the relativistic velocity addition formula
-pub fn add_velocities3(v1: &Velocity, v2: &Velocity) -> Velocity {
-    let u = validate_velocity(v1).get::<meter_per_second>();
-    let v = validate_velocity(v2).get::<meter_per_second>();
+pub fn add_velocities3(config: &BigRationalConfig, v1: &Velocity, v2: &Velocity) -> Velocity {
+    let u = validate_velocity(config, v1).get::<meter_per_second>();
+    let v = validate_velocity(config, v2).get::<meter_per_second>();
     let added = u.clone() + v.clone();
 
-    let resulting_velocity = added / (BigRational::one() + (u * v / &*C_SQUARED_BR));
-    Velocity::new::<meter_per_second>(resulting_velocity)
+    let resulting_velocity = added / (BigRational::one() + (u * v / &config.c_squared));
+    Velocity::new::<meter_per_second>(config.rationalize(&resulting_velocity))
 }
 
 // ========== Conversion functions ==========
@@ -373,22 +412,46 @@ pub fn bigrational_to_f64(n: &BigRational) -> f64 {
 pub struct BigRationalConfig {
     pub max_iterations: u32,
     pub precision: u32,
+    /// Speed of light in whatever unit system this config represents
+    pub c: BigRational,
+    /// c squared, cached since almost every relativistic formula needs it
+    pub c_squared: BigRational,
 }
 
 impl BigRationalConfig {
-    /// Setup a new config object
+    /// Setup a new config object using the real (SI) speed of light
     pub fn new() -> Self {
+        Self::si()
+    }
+
+    /// Setup with specified precision, using the real (SI) speed of light
+    pub fn new_with_precision(precision: u32) -> Self {
         BigRationalConfig {
-            max_iterations: 500,
-            precision: 100,
+            precision,
+            ..Self::si()
         }
     }
 
-    /// Setup with specified precision
-    pub fn new_with_precision(precision: u32) -> Self {
+    /// SI units: c = 299,792,458 m/s, the real speed of light
+    pub fn si() -> Self {
+        Self::with_c(C_BR.clone())
+    }
+
+    /// Natural units: c = 1, so a "velocity" is already expressed as a fraction of c
+    /// and the rationals involved stay small
+    pub fn natural() -> Self {
+        Self::with_c(BigRational::one())
+    }
+
+    /// Build a config around an arbitrary speed of light, e.g. a scaled "game" c for a
+    /// HUD speedometer, or geometrized units
+    pub fn with_c(c: BigRational) -> Self {
+        let c_squared = &c * &c;
         BigRationalConfig {
             max_iterations: 500,
-            precision,
+            precision: 100,
+            c,
+            c_squared,
         }
     }
 
@@ -407,7 +470,57 @@ impl BigRationalConfig {
                 break;
             }
         }
-        sum
+        self.rationalize(&sum)
+    }
+
+    /// Find a small-denominator rational within the configured `precision` tolerance of
+    /// `r`, via the continued-fraction convergent algorithm.
+    /// Without this, repeated `sqrt`/`tanh`/`atanh`/`exp` calls produce rationals whose
+    /// numerator and denominator grow without bound, since every arithmetic operation
+    /// on exact fractions keeps all of their precision rather than rounding it away.
+    ///
+    /// Given `x = r`, repeatedly takes `a = floor(x)`, then sets `x = 1/(x - a)`,
+    /// maintaining convergent recurrences `p_i = a_i*p_{i-1} + p_{i-2}` and
+    /// `q_i = a_i*q_{i-1} + q_{i-2}` (seeded `p_{-1}=1, p_{-2}=0, q_{-1}=0, q_{-2}=1`).
+    /// The first convergent within `eps = 1/10^precision` of `r` is returned. Only
+    /// convergents are tried, so an intermediate fraction between two convergents
+    /// (e.g. `3/4` for `r = 5/6`, `eps = 0.09`, where `5/6` itself is returned) can meet
+    /// the tolerance with a smaller denominator than the result.
+    pub fn rationalize(&self, r: &BigRational) -> BigRational {
+        let eps = BigRational::new(BigInt::one(), BigInt::from(10).pow(self.precision));
+
+        // factor out the sign up front so the continued-fraction expansion below only
+        // has to deal with non-negative values
+        let negative = r.is_negative();
+        let target = r.abs();
+
+        let mut p_prev2 = BigInt::zero();
+        let mut p_prev1 = BigInt::one();
+        let mut q_prev2 = BigInt::one();
+        let mut q_prev1 = BigInt::zero();
+        let mut x = target.clone();
+
+        for _ in 0..self.max_iterations {
+            let a = x.to_integer();
+            let p = &a * &p_prev1 + &p_prev2;
+            let q = &a * &q_prev1 + &q_prev2;
+            let convergent = BigRational::new(p.clone(), q.clone());
+
+            let remainder = &x - BigRational::from_integer(a);
+            if remainder.is_zero() || (&convergent - &target).abs() < eps {
+                return if negative { -convergent } else { convergent };
+            }
+
+            p_prev2 = p_prev1;
+            p_prev1 = p;
+            q_prev2 = q_prev1;
+            q_prev1 = q;
+            x = BigRational::one() / remainder;
+        }
+
+        // max_iterations exceeded: return the best convergent found so far
+        let convergent = BigRational::new(p_prev1, q_prev1);
+        if negative { -convergent } else { convergent }
     }
 
     /// Convert a `BigRational` to a decimal string with a specified number of digits after the decimal.
@@ -477,7 +590,7 @@ impl BigRationalConfig {
             c += 1;
         }
 
-        x
+        self.rationalize(&x)
     }
 
     /// Calculate the hyperbolic tangent of a `BigRational` with a maximum of 100 iterations
@@ -496,15 +609,29 @@ impl BigRationalConfig {
         let one = BigRational::from_integer(1.into());
 
         // tanh(x) = (e^(2x) - 1)/(e^(2x) + 1)
-        (exp_x.clone() - one.clone()) / (exp_x + one)
+        let result = (exp_x.clone() - one.clone()) / (exp_x + one);
+        self.rationalize(&result)
     }
 
-    /// Calculate the hyperbolic arctangent of a `BigRational` with a maximum of 100 iterations
+    /// Calculate the hyperbolic arctangent of a `BigRational` via `atanh(x) =
+    /// 0.5*ln((1+x)/(1-x))`. The Taylor series this used to be backed by converged
+    /// catastrophically slowly as `|x| -> 1` (exactly the ultra-relativistic regime this
+    /// is needed for); going through `ln`'s range reduction instead keeps iteration
+    /// counts low and bounded no matter how close x gets to ±1
     pub fn atanh(&self, x: &BigRational) -> BigRational {
-        // Check domain validity
-        let one = BigRational::from_integer(1.into());
+        let one = BigRational::one();
         assert!(x.abs() < one, "atanh(x) is only defined for |x| < 1");
 
+        let half = BigRational::new(BigInt::one(), BigInt::from(2));
+        let ratio = (&one + x) / (&one - x);
+        self.rationalize(&(half * self.ln(&ratio)))
+    }
+
+    /// Taylor series for `atanh`, used internally to seed `ln` once its range reduction
+    /// has bounded the argument to `|x| <= 1/3`, where it converges in a handful of
+    /// iterations. Not exposed publicly: for general `|x| -> 1` this is the slow path
+    /// that the public `atanh` exists to avoid
+    fn atanh_series(&self, x: &BigRational) -> BigRational {
         let eps = BigRational::new(BigInt::one(), BigInt::from(10).pow(self.precision));
         let mut result = BigRational::new(0.into(), 1.into());
         let mut term = x.clone();
@@ -525,6 +652,268 @@ impl BigRationalConfig {
 
         result
     }
+
+    /// Calculate the hyperbolic cosine of a `BigRational`: `(e^x + e^-x) / 2`
+    pub fn cosh(&self, x: &BigRational) -> BigRational {
+        let two = BigRational::from_integer(2.into());
+        let result = (self.exp(x) + self.exp(&-x)) / two;
+        self.rationalize(&result)
+    }
+
+    /// Calculate the hyperbolic sine of a `BigRational`: `(e^x - e^-x) / 2`
+    pub fn sinh(&self, x: &BigRational) -> BigRational {
+        let two = BigRational::from_integer(2.into());
+        let result = (self.exp(x) - self.exp(&-x)) / two;
+        self.rationalize(&result)
+    }
+
+    /// Calculate the natural logarithm of a positive `BigRational` via range reduction:
+    /// factor `x = m * 2^k` so `m` is in `[1, 2)`, then return `k*ln(2) + ln(m)`. This
+    /// keeps the argument passed to `ln_reduced` small and bounded regardless of how
+    /// large or small `x` is, so it converges in a handful of iterations either way
+    pub fn ln(&self, x: &BigRational) -> BigRational {
+        assert!(x.is_positive(), "ln(x) is only defined for x > 0");
+
+        let two = BigRational::from_integer(2.into());
+        let one = BigRational::one();
+        let mut k: i64 = 0;
+        let mut m = x.clone();
+
+        while m >= two {
+            m = &m / &two;
+            k += 1;
+        }
+        while m < one {
+            m = &m * &two;
+            k -= 1;
+        }
+
+        let ln_m = self.ln_reduced(&m);
+        if k == 0 {
+            self.rationalize(&ln_m)
+        } else {
+            let result = BigRational::from_integer(k.into()) * self.ln2() + ln_m;
+            self.rationalize(&result)
+        }
+    }
+
+    /// `ln(2)`, via the fast-converging identity `ln(2) = 2*atanh(1/3)`, since
+    /// `(2-1)/(2+1) = 1/3`. The value is not cached: it is recomputed on every call
+    fn ln2(&self) -> BigRational {
+        let one_third = BigRational::new(BigInt::one(), BigInt::from(3));
+        let two = BigRational::from_integer(2.into());
+        two * self.atanh_series(&one_third)
+    }
+
+    /// `ln(m)` for `m` already reduced into `[1, 2)`, via `ln(m) = 2*atanh((m-1)/(m+1))`.
+    /// That argument is at most `1/3`, so the underlying Taylor series converges
+    /// quickly no matter how close the original value was to 1
+    fn ln_reduced(&self, m: &BigRational) -> BigRational {
+        let one = BigRational::one();
+        let two = BigRational::from_integer(2.into());
+        let arg = (m - &one) / (m + &one);
+        two * self.atanh_series(&arg)
+    }
+}
+
+// =================================================================================================
+// Human-readable formatting: auto-scaled strings for demo/CLI callers, instead of
+// manually juggling `get::<meter_per_second>()` and friends
+
+const JOULES_PER_MEGATON_TNT: f64 = 4.184e15;
+const METERS_PER_AU: f64 = 1.495_978_707e11;
+const METERS_PER_LIGHT_YEAR: f64 = 9.460_730_472_580_8e15;
+
+/// Render a velocity as a human-readable, auto-scaled string: plain m/s or km/h for
+/// everyday speeds, km/s for intermediate orbital-class speeds, and "fraction of c" or
+/// "(1 - eps)*c" notation as the speed approaches light-speed (so an ultra-relativistic
+/// velocity doesn't just round down to "1.000000c")
+pub fn readable_velocity(config: &BigRationalConfig, v: &Velocity) -> String {
+    let c = bigrational_to_f64(&config.c);
+    let ms = bigrational_to_f64(&v.get::<meter_per_second>());
+    let fraction = ms / c;
+    let abs_fraction = fraction.abs();
+
+    if abs_fraction >= 0.999 {
+        let eps = 1.0 - abs_fraction;
+        let sign = if fraction.is_sign_negative() { "-" } else { "" };
+        if eps <= 0.0 {
+            format!("{sign}c")
+        } else {
+            format!("{sign}(1 - {eps:.3e})*c")
+        }
+    } else if abs_fraction >= 0.01 {
+        format!("{fraction:.6}c")
+    } else if ms.abs() >= 1000.0 {
+        format!("{:.3} km/s", ms / 1000.0)
+    } else if ms.abs() >= 50.0 {
+        format!("{:.3} km/h", ms * 3.6)
+    } else {
+        format!("{ms:.3} m/s")
+    }
+}
+
+/// Render an energy as a human-readable, auto-scaled string: joules for everyday
+/// magnitudes, switching to megatons of TNT equivalent once the value is large enough
+/// that joules stop being a meaningful unit (as happens with relativistic mass-energy)
+pub fn readable_energy(e: &Energy) -> String {
+    let joules = bigrational_to_f64(&e.get::<joule>());
+    if joules.abs() >= JOULES_PER_MEGATON_TNT / 1000.0 {
+        format!("{:.3} Mt TNT", joules / JOULES_PER_MEGATON_TNT)
+    } else {
+        format!("{joules:.3} J")
+    }
+}
+
+/// Render a length as a human-readable, auto-scaled string: meters or kilometers for
+/// everyday and planetary scales, switching to astronomical units then light-years as
+/// the magnitude grows, so contracted-length results stay readable at any scale
+pub fn readable_length(len: &Length) -> String {
+    let meters = bigrational_to_f64(&len.get::<meter>());
+
+    if meters.abs() >= METERS_PER_LIGHT_YEAR / 10.0 {
+        format!("{:.6} ly", meters / METERS_PER_LIGHT_YEAR)
+    } else if meters.abs() >= METERS_PER_AU / 10.0 {
+        format!("{:.6} AU", meters / METERS_PER_AU)
+    } else if meters.abs() >= 1000.0 {
+        format!("{:.3} km", meters / 1000.0)
+    } else {
+        format!("{meters:.3} m")
+    }
+}
+
+// =================================================================================================
+// Parsing: shorthand physics notation ("0.9c", "1g", "365 days") into the strongly-typed
+// quantities, for CLI or config-file front ends. Numeric parts are parsed straight into
+// an exact `BigRational`, never through a lossy `f64`
+
+const SECONDS_PER_DAY: u64 = 86_400;
+const SECONDS_PER_YEAR: u64 = 31_557_600; // 365.25 * 24 * 60 * 60, matching the Julian year used elsewhere
+
+/// Split a shorthand quantity like `"0.9c"` or `"2.5e8 m/s"` into its numeric and unit
+/// parts: first on whitespace if there is any, otherwise at the boundary where the
+/// trailing run of letters begins
+fn split_number_and_unit(s: &str) -> (&str, &str) {
+    let s = s.trim();
+    if let Some(idx) = s.find(char::is_whitespace) {
+        let (num, unit) = s.split_at(idx);
+        return (num.trim(), unit.trim());
+    }
+
+    let split_at = s
+        .rfind(|c: char| !c.is_ascii_alphabetic())
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    s.split_at(split_at)
+}
+
+/// Parse a decimal string with an optional sign and `e`/`E` exponent into an exact
+/// `BigRational`, without ever going through a lossy `f64`: the integer and fractional
+/// parts are split on the decimal point and the denominator is built from the digit
+/// count, so `"0.1"` becomes exactly `1/10` rather than the nearest double
+fn bigrational_from_decimal_str(s: &str) -> anyhow::Result<BigRational> {
+    let s = s.trim();
+    if s.is_empty() {
+        anyhow::bail!("empty numeric value");
+    }
+
+    let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+        Some((m, e)) => {
+            let exponent: i32 = e
+                .parse()
+                .map_err(|_| anyhow::anyhow!("invalid exponent in \"{s}\""))?;
+            (m, exponent)
+        }
+        None => (s, 0),
+    };
+
+    let (is_negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+
+    let (int_part, frac_part) = match mantissa.split_once('.') {
+        Some((i, f)) => (i, f),
+        None => (mantissa, ""),
+    };
+
+    if int_part.is_empty() && frac_part.is_empty() {
+        anyhow::bail!("invalid numeric value \"{s}\"");
+    }
+    if !int_part.bytes().all(|b| b.is_ascii_digit()) || !frac_part.bytes().all(|b| b.is_ascii_digit()) {
+        anyhow::bail!("invalid numeric value \"{s}\"");
+    }
+
+    let digits = format!("{int_part}{frac_part}");
+    let numerator: BigInt = digits
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid numeric value \"{s}\""))?;
+    let numerator = if is_negative { -numerator } else { numerator };
+    let denominator = BigInt::from(10).pow(frac_part.len() as u32);
+    let value = BigRational::new(numerator, denominator);
+
+    match exponent.cmp(&0) {
+        std::cmp::Ordering::Equal => Ok(value),
+        std::cmp::Ordering::Greater => {
+            Ok(value * BigRational::from_integer(BigInt::from(10).pow(exponent as u32)))
+        }
+        std::cmp::Ordering::Less => {
+            Ok(value / BigRational::from_integer(BigInt::from(10).pow((-exponent) as u32)))
+        }
+    }
+}
+
+/// Parse a velocity from shorthand notation: `"0.9c"` (fraction of the configured speed
+/// of light), `"2.5e8 m/s"`, or `"670 mph"`. Rejects speeds at or beyond `c` via
+/// `validate_velocity_result`
+pub fn parse_velocity(config: &BigRationalConfig, s: &str) -> anyhow::Result<Velocity> {
+    let (number, unit) = split_number_and_unit(s);
+    let value = bigrational_from_decimal_str(number)?;
+
+    let meters_per_second = match unit {
+        "c" => value * &config.c,
+        "m/s" | "" => value,
+        "km/h" | "kph" => value * BigRational::new(BigInt::from(1000), BigInt::from(3600)),
+        "mph" => {
+            value * bigrational_from_ratio(1_609_344u64, 3_600_000u64) // 1 mile = 1,609,344 mm
+        }
+        other => anyhow::bail!("unrecognised velocity unit \"{other}\" in \"{s}\""),
+    };
+
+    validate_velocity_result(config, &Velocity::new::<meter_per_second>(meters_per_second))
+        .map(|v| v.clone())
+}
+
+/// Parse an acceleration from shorthand notation: `"1g"` (standard gravity) or
+/// `"9.8 m/s^2"`
+pub fn parse_acceleration(s: &str) -> anyhow::Result<Acceleration> {
+    let (number, unit) = split_number_and_unit(s);
+    let value = bigrational_from_decimal_str(number)?;
+
+    let meters_per_second_squared = match unit {
+        "g" => value * &*STANDARD_GRAVITY_BR,
+        "m/s^2" | "m/s2" | "" => value,
+        other => anyhow::bail!("unrecognised acceleration unit \"{other}\" in \"{s}\""),
+    };
+
+    Ok(Acceleration::new::<meter_per_second_squared>(
+        meters_per_second_squared,
+    ))
+}
+
+/// Parse a time duration from shorthand notation: `"365 days"`, `"1 yr"`, or `"3600 s"`
+pub fn parse_time(s: &str) -> anyhow::Result<Time> {
+    let (number, unit) = split_number_and_unit(s);
+    let value = bigrational_from_decimal_str(number)?;
+
+    let seconds = match unit {
+        "s" | "sec" | "secs" | "second" | "seconds" | "" => value,
+        "day" | "days" => value * BigRational::from_integer(SECONDS_PER_DAY.into()),
+        "yr" | "year" | "years" => value * BigRational::from_integer(SECONDS_PER_YEAR.into()),
+        other => anyhow::bail!("unrecognised time unit \"{other}\" in \"{s}\""),
+    };
+
+    Ok(Time::new::<second>(seconds))
 }
 
 // ========== Math functions unsupported on BigRational ==========